@@ -0,0 +1,173 @@
+//! Benchmark for the Conway Cube step. Runs of increasing numbers of steps are timed in 3D and 4D
+//! from the AOC 2020 Day 17 puzzle input (where the number of active cubes grows rapidly), and in
+//! 4D from a small seed that settles into a stable pattern (where the cost of each step should stay
+//! constant however many steps are conducted). Each run is timed using the baseline dense
+//! implementation (which also stores every inactive cube next to an active cube, and recounts the
+//! neighbours of each stored cube separately), and using ConwayCube both with and without its
+//! mirror symmetry being exploited. Run with "cargo bench --bench conway_cube".
+
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
+use std::time::Instant;
+
+use advent_of_code_2020::utils::machines::ConwayCube;
+use advent_of_code_2020::utils::machines::LifeRule;
+use advent_of_code_2020::utils::map::Neighbourhood;
+use advent_of_code_2020::utils::map::Point;
+
+/// Location of the puzzle input used as the initial slice of the growing Conway Cubes.
+const INPUT_PATH: &str = "input/2020/day17.txt";
+/// Initial slice of the Conway Cube that settles into a stable pattern of 64 active cubes in 4D.
+const STABLE_SEED: &str = ".#.\n###\n.#.";
+/// Largest number of steps for which the baseline implementation is timed, as it slows down with
+/// every step conducted.
+const BASELINE_MAX_STEPS: u64 = 10;
+/// Largest number of dimensions for which the baseline implementation is timed (taking minutes for
+/// six steps in 5D).
+const BASELINE_MAX_DIMS: usize = 4;
+
+/// Baseline Conway Cube implementation, storing the state of every cube next to an active cube
+/// (including those no longer near any active cube) and counting the active neighbours of each
+/// stored cube separately in each step.
+struct DenseConwayCube<const D: usize> {
+    grid_state: HashMap<Point<D>, bool>,
+    rule: LifeRule,
+    neighbourhood: Neighbourhood,
+}
+
+impl<const D: usize> DenseConwayCube<D> {
+    /// Creates a new Conway Cube from the raw input representing a section of the X-Y plane.
+    fn new(raw_input: &str) -> Self {
+        let mut grid_state: HashMap<Point<D>, bool> = HashMap::new();
+        for (y, line) in raw_input.lines().map(|line| line.trim()).enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let mut loc = Point::origin();
+                loc[0] = x as i64;
+                loc[1] = y as i64;
+                grid_state.insert(loc, c == '#');
+            }
+        }
+        Self {
+            grid_state,
+            rule: LifeRule::conway(),
+            neighbourhood: Neighbourhood::moore(),
+        }
+    }
+
+    /// Counts the number of cubes in the active state.
+    fn count_active_cubes(&self) -> u64 {
+        self.grid_state.values().filter(|active| **active).count() as u64
+    }
+
+    /// Conducts a single step, after storing every inactive cube next to a stored cube.
+    fn conduct_step(&mut self) {
+        let mut new_inactive_points: Vec<Point<D>> = vec![];
+        for loc in self.grid_state.keys() {
+            for neighbour in self.neighbourhood.iter(loc) {
+                if !self.grid_state.contains_key(&neighbour) {
+                    new_inactive_points.push(neighbour);
+                }
+            }
+        }
+        for loc in new_inactive_points {
+            self.grid_state.insert(loc, false);
+        }
+        let mut to_toggle: Vec<Point<D>> = vec![];
+        for (loc, active) in self.grid_state.iter() {
+            let count = self
+                .neighbourhood
+                .iter(loc)
+                .filter(|neighbour| self.grid_state.get(neighbour) == Some(&true))
+                .count() as u64;
+            if self.rule.next_state(*active, count) != *active {
+                to_toggle.push(*loc);
+            }
+        }
+        for loc in to_toggle {
+            let active = self.grid_state[&loc];
+            self.grid_state.insert(loc, !active);
+        }
+    }
+}
+
+fn main() {
+    let input = fs::read_to_string(INPUT_PATH)
+        .unwrap_or_else(|e| panic!("Benchmark - failed to read \"{}\": {}", INPUT_PATH, e));
+    println!(
+        "{:>8} {:>4} {:>6} {:>12} {:>14} {:>14} {:>14}",
+        "seed", "dims", "steps", "active", "baseline", "active set", "mirrored"
+    );
+    for steps in [6, 10, 14].iter() {
+        report("day17", 3, *steps, time_steps::<3>(&input, *steps));
+    }
+    for steps in [6, 10, 14].iter() {
+        report("day17", 4, *steps, time_steps::<4>(&input, *steps));
+    }
+    for steps in [6, 8].iter() {
+        report("day17", 5, *steps, time_steps::<5>(&input, *steps));
+    }
+    for steps in [10, 100, 200, 500].iter() {
+        report("stable", 4, *steps, time_steps::<4>(STABLE_SEED, *steps));
+    }
+}
+
+/// Creates a Conway Cube with the given number of dimensions from the input and conducts the given
+/// number of steps using the baseline implementation (if there are few enough steps and
+/// dimensions), using ConwayCube in full and using ConwayCube exploiting mirror symmetry. Returns
+/// the number of active cubes at the end and the time taken by each.
+///
+/// Panics if the simulations end with different numbers of active cubes.
+fn time_steps<const D: usize>(input: &str, steps: u64)
+        -> (u64, Option<Duration>, Duration, Duration)
+{
+    let start = Instant::now();
+    let mut conway_cube = ConwayCube::<D>::new(input);
+    for _ in 0..steps {
+        conway_cube.conduct_step();
+    }
+    let full_time = start.elapsed();
+    let start = Instant::now();
+    let mut mirrored_cube = ConwayCube::<D>::new(input).with_mirror_symmetry();
+    for _ in 0..steps {
+        mirrored_cube.conduct_step();
+    }
+    let mirrored_time = start.elapsed();
+    let active = conway_cube.count_active_cubes();
+    if active != mirrored_cube.count_active_cubes() {
+        panic!("Benchmark - mirrored Conway Cube differs after {} steps", steps);
+    }
+    if steps > BASELINE_MAX_STEPS || D > BASELINE_MAX_DIMS {
+        return (active, None, full_time, mirrored_time);
+    }
+    let start = Instant::now();
+    let mut baseline_cube = DenseConwayCube::<D>::new(input);
+    for _ in 0..steps {
+        baseline_cube.conduct_step();
+    }
+    let baseline_time = start.elapsed();
+    if active != baseline_cube.count_active_cubes() {
+        panic!("Benchmark - baseline Conway Cube differs after {} steps", steps);
+    }
+    (active, Some(baseline_time), full_time, mirrored_time)
+}
+
+/// Prints a row of the results table, with the speedups of ConwayCube over the baseline.
+fn report(seed: &str, dims: usize, steps: u64, times: (u64, Option<Duration>, Duration, Duration)) {
+    let (active, baseline_time, full_time, mirrored_time) = times;
+    let baseline = match baseline_time {
+        Some(baseline_time) => format!("{:?}", baseline_time),
+        None => String::from("-"),
+    };
+    println!(
+        "{:>8} {:>4} {:>6} {:>12} {:>14} {:>14?} {:>14?}",
+        seed, dims, steps, active, baseline, full_time, mirrored_time
+    );
+    if let Some(baseline_time) = baseline_time {
+        let speedup = |time: Duration| baseline_time.as_secs_f64() / time.as_secs_f64();
+        println!(
+            "{:>8} {:>4} {:>6} {:>12} {:>14} {:>13.1}x {:>13.1}x",
+            "", "", "", "", "speedup", speedup(full_time), speedup(mirrored_time)
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use super::error::SolutionError;
+use super::registry::SolutionEntry;
+
+use serde::Serialize;
+
+/// Summary of the times recorded over repeated runs of a generator or solver, in nanoseconds.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct TimingSummary {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl TimingSummary {
+    /// Summarises the given times. Returns None if no times are given. The median of an even
+    /// number of times is taken as the mean of the two middle times.
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        let mut nanos = durations.iter().map(|x| x.as_nanos() as u64).collect::<Vec<u64>>();
+        nanos.sort_unstable();
+        let mid = nanos.len() / 2;
+        let median = if nanos.len() % 2 == 0 {
+            (nanos[mid - 1] + nanos[mid]) / 2
+        } else {
+            nanos[mid]
+        };
+        Some(Self {
+            min: nanos[0],
+            median,
+            max: nanos[nanos.len() - 1],
+        })
+    }
+}
+
+/// Timing results from benchmarking a single puzzle part against its input.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct BenchmarkResult {
+    pub day: u32,
+    pub part: u32,
+    pub runs: usize,
+    pub answer: String,
+    pub generator_ns: TimingSummary,
+    pub solver_ns: TimingSummary,
+}
+
+/// Machine-readable report of the benchmark results for a set of puzzle parts.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct BenchmarkReport {
+    pub year: u32,
+    pub results: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkReport {
+    /// Creates a new report for the given year, with no results recorded.
+    pub fn new(year: u32) -> Self {
+        Self {
+            year,
+            results: vec![],
+        }
+    }
+
+    /// Adds the given result to the report.
+    pub fn add_result(&mut self, result: BenchmarkResult) {
+        self.results.push(result);
+    }
+
+    /// Converts the report to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+/// Runs the generator and solver of the given puzzle part against the input the given number of
+/// times (at least once), recording the time taken by each on every run. Returns an error if the
+/// input could not be parsed or the puzzle does not have the part.
+pub fn run_benchmark(entry: &SolutionEntry, input: &str, runs: usize)
+        -> Result<BenchmarkResult, SolutionError>
+{
+    let runs = runs.max(1);
+    let mut generator_times: Vec<Duration> = vec![];
+    let mut solver_times: Vec<Duration> = vec![];
+    let mut answer = String::new();
+    for _ in 0..runs {
+        let run = entry.run(input)?;
+        generator_times.push(run.generator_time);
+        solver_times.push(run.solver_time);
+        answer = run.answer.to_string();
+    }
+    Ok(BenchmarkResult {
+        day: entry.get_day(),
+        part: entry.get_part(),
+        runs,
+        answer,
+        generator_ns: TimingSummary::from_durations(&generator_times).unwrap(),
+        solver_ns: TimingSummary::from_durations(&solver_times).unwrap(),
+    })
+}
@@ -1,19 +1,28 @@
+use super::error::ParseError;
+
+/// Solution for AOC 2020 Day 1 (Report Repair).
+pub struct Day01;
+
+impl_solution!(Day01, day = 1, input = Vec<u64>);
+
 #[aoc_generator(day1)]
-fn generate_input(input: &str) -> Vec<u64> {
+fn generate_input(input: &str) -> Result<Vec<u64>, ParseError> {
     let mut output: Vec<u64> = vec![];
-    for line in input.lines() {
-        let line = line.trim();
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
-        let value = line.parse::<u64>().unwrap();
-        output.push(value);
+        match line.parse::<u64>() {
+            Ok(value) => output.push(value),
+            Err(_) => return Err(ParseError::at_line(1, i, raw_line, "expense is not a number")),
+        }
     }
-    return output;
+    Ok(output)
 }
 
 #[aoc(day1, part1)]
-fn solve_part_1(values: &Vec<u64>) -> u64 {
+fn solve_part_1(values: &[u64]) -> u64 {
     for i in 0..values.len() {
         // Check values ahead of first index, to prevent duplication of work
         for j in (i+1)..values.len() {
@@ -26,7 +35,7 @@ fn solve_part_1(values: &Vec<u64>) -> u64 {
 }
 
 #[aoc(day1, part2)]
-fn solve_part_2(values: &Vec<u64>) -> u64 {
+fn solve_part_2(values: &[u64]) -> u64 {
     for i in 0..values.len() {
         for j in (i + 1)..values.len() {
             for k in (j + 1)..values.len() {
@@ -38,22 +47,3 @@ fn solve_part_2(values: &Vec<u64>) -> u64 {
     }
     panic!("Day 1 Part 2 - should not get here!");
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d01_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day1.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(996075, result);
-    }
-
-    #[test]
-    fn test_d01_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day1.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(51810360, result);
-    }
-}
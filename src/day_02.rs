@@ -1,28 +1,49 @@
+use super::error::ParseError;
+
 use regex::Regex;
 
+/// Password policy (lower and upper positions, policy character) followed by the password.
+type PasswordEntry = (usize, usize, char, Vec<char>);
+
+/// Solution for AOC 2020 Day 2 (Password Philosophy).
+pub struct Day02;
+
+impl_solution!(Day02, day = 2, input = Vec<PasswordEntry>);
+
 #[aoc_generator(day2)]
-fn generate_input(input: &str) -> Vec<(usize, usize, char, Vec<char>)> {
-    let mut output: Vec<(usize, usize, char, Vec<char>)> = vec![];
-    let password_regex = Regex::new(r"(\d+)-(\d+) ([a-z]): ([a-z]+)").unwrap();
-    for line in input.lines() {
+fn generate_input(input: &str) -> Result<Vec<PasswordEntry>, ParseError> {
+    let mut output: Vec<PasswordEntry> = vec![];
+    let password_regex = Regex::new(r"^(\d+)-(\d+) ([a-z]): ([a-z]+)$").unwrap();
+    for (i, raw_line) in input.lines().enumerate() {
         // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
         // Match line against regex, then extract fields to process
-        let captures = password_regex.captures(line).unwrap();
-        let lower = captures[1].parse::<usize>().unwrap();
-        let upper = captures[2].parse::<usize>().unwrap();
+        let captures = match password_regex.captures(line) {
+            Some(captures) => captures,
+            None => return Err(ParseError::at_line(2, i, raw_line, "malformed password entry")),
+        };
+        let lower = parse_position(&captures[1], i, raw_line)?;
+        let upper = parse_position(&captures[2], i, raw_line)?;
         let c = captures[3].chars().next().unwrap();
         let password = captures[4].chars().collect::<Vec<char>>();
         output.push((lower, upper, c, password));
     }
-    return output;
+    Ok(output)
+}
+
+/// Parses a policy position from a password entry, which must be at least 1.
+fn parse_position(text: &str, line_index: usize, line: &str) -> Result<usize, ParseError> {
+    match text.parse::<usize>() {
+        Ok(value) if value > 0 => Ok(value),
+        _ => Err(ParseError::at_text(2, line_index, line, text, "invalid policy position")),
+    }
 }
 
 #[aoc(day2, part1)]
-fn solve_part_1(input: &Vec<(usize, usize, char, Vec<char>)>) -> u64 {
+fn solve_part_1(input: &[PasswordEntry]) -> u64 {
     let mut valid_count = 0;
     for (lower, upper, c, password) in input {
         // Count number of times check character occurs in password
@@ -32,11 +53,11 @@ fn solve_part_1(input: &Vec<(usize, usize, char, Vec<char>)>) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
+    valid_count
 }
 
 #[aoc(day2, part2)]
-fn solve_part_2(input: &Vec<(usize, usize, char, Vec<char>)>) -> u64 {
+fn solve_part_2(input: &[PasswordEntry]) -> u64 {
     let mut valid_count = 0;
     for (lower, upper, c, password) in input {
         // Adjust lower and upper indices to accomodate for off-by-one
@@ -44,10 +65,10 @@ fn solve_part_2(input: &Vec<(usize, usize, char, Vec<char>)>) -> u64 {
         let upper = upper - 1;
         let mut count = 0;
         // Check if validation character present at lower and upper indices
-        if lower <= password.len() - 1 && &password[lower] == c {
+        if lower < password.len() && &password[lower] == c {
             count += 1;
         }
-        if upper <= password.len() - 1 && &password[upper] == c {
+        if upper < password.len() && &password[upper] == c {
             count += 1;
         }
         // Increment valid count if check character is at exactly one of the specified indices
@@ -55,24 +76,5 @@ fn solve_part_2(input: &Vec<(usize, usize, char, Vec<char>)>) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d02_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day2.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(454, result);
-    }
-
-    #[test]
-    fn test_d02_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day2.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(649, result);
-    }
+    valid_count
 }
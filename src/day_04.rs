@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
+use super::error::ParseError;
+
 use enum_iterator::IntoEnumIterator;
 use regex::Regex;
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug, IntoEnumIterator)]
-enum PassportField {
+pub enum PassportField {
     BirthYear,
     IssueYear,
     ExpirationYear,
@@ -18,23 +20,28 @@ enum PassportField {
 impl PassportField {
     fn from_string(input: &str) -> Option<PassportField> {
         match input {
-            "byr" => return Some(PassportField::BirthYear),
-            "iyr" => return Some(PassportField::IssueYear),
-            "eyr" => return Some(PassportField::ExpirationYear),
-            "hgt" => return Some(PassportField::Height),
-            "hcl" => return Some(PassportField::HairColour),
-            "ecl" => return Some(PassportField::EyeColour),
-            "pid" => return Some(PassportField::PassportID),
-            "cid" => return Some(PassportField::CountryID),
-            _ => return None,
+            "byr" => Some(PassportField::BirthYear),
+            "iyr" => Some(PassportField::IssueYear),
+            "eyr" => Some(PassportField::ExpirationYear),
+            "hgt" => Some(PassportField::Height),
+            "hcl" => Some(PassportField::HairColour),
+            "ecl" => Some(PassportField::EyeColour),
+            "pid" => Some(PassportField::PassportID),
+            "cid" => Some(PassportField::CountryID),
+            _ => None,
         }
     }
 }
 
+/// Solution for AOC 2020 Day 4 (Passport Processing).
+pub struct Day04;
+
+impl_solution!(Day04, day = 4, input = Vec<HashMap<PassportField, String>>);
+
 #[aoc_generator(day4)]
-fn generate_input(input: &str) -> Vec<HashMap<PassportField, String>> {
+fn generate_input(input: &str) -> Result<Vec<HashMap<PassportField, String>>, ParseError> {
     let mut passports: Vec<HashMap<PassportField, String>> = vec![];
-    let mut lines = input.lines();
+    let mut lines = input.lines().enumerate();
     let field_regex = Regex::new(r"(byr|iyr|eyr|hgt|hcl|ecl|pid|cid):(.*)").unwrap();
     // Outer loop - process all blocks representing passports
     loop {
@@ -49,7 +56,8 @@ fn generate_input(input: &str) -> Vec<HashMap<PassportField, String>> {
                 break;
             }
             // Check if blank line reached, indicating end of current passport
-            let line = line.unwrap().trim();
+            let (i, raw_line) = line.unwrap();
+            let line = raw_line.trim();
             if line.is_empty() {
                 break;
             }
@@ -62,20 +70,22 @@ fn generate_input(input: &str) -> Vec<HashMap<PassportField, String>> {
                     let value = captures[2].to_string();
                     passport.insert(field, value);
                 } else {
-                    panic!("Day 4 - malformed input file!");
+                    return Err(ParseError::at_text(
+                        4, i, raw_line, pair, "malformed passport field"
+                    ));
                 }
             }
         }
         passports.push(passport);
-        if end_of_file == true {
+        if end_of_file {
             break;
         }
     }
-    return passports;
+    Ok(passports)
 }
 
 #[aoc(day4, part1)]
-fn solve_part_1(passports: &Vec<HashMap<PassportField, String>>) -> u64 {
+fn solve_part_1(passports: &[HashMap<PassportField, String>]) -> u64 {
     let mut valid_count = 0;
     for passport in passports {
         let mut valid = true;
@@ -93,11 +103,11 @@ fn solve_part_1(passports: &Vec<HashMap<PassportField, String>>) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
+    valid_count
 }
 
 #[aoc(day4, part2)]
-fn solve_part_2(passports: &Vec<HashMap<PassportField, String>>) -> u64 {
+fn solve_part_2(passports: &[HashMap<PassportField, String>]) -> u64 {
     let mut valid_count = 0;
     // Create regexes to help with validity checking
     let byr_regex = Regex::new(r"^19[2-9][0-9]|200[0-2]$").unwrap();
@@ -146,24 +156,5 @@ fn solve_part_2(passports: &Vec<HashMap<PassportField, String>>) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d04_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day4.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(264, result);
-    }
-
-    #[test]
-    fn test_d04_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day4.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(224, result);
-    }
+    valid_count
 }
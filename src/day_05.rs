@@ -1,29 +1,44 @@
+use super::error::ParseError;
+
+/// Solution for AOC 2020 Day 5 (Binary Boarding).
+pub struct Day05;
+
+impl_solution!(Day05, day = 5, input = Vec<u64>);
+
 #[aoc_generator(day5)]
-fn generate_input(input: &str) -> Vec<u64> {
+fn generate_input(input: &str) -> Result<Vec<u64>, ParseError> {
     let mut seat_specs: Vec<u64> = vec![];
-    for line in input.lines() {
-        let line = line.trim();
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
         if line.len() != 10 {
-            panic!("Day 5 - input line length incorrect!");
+            return Err(ParseError::at_line(5, i, raw_line, "seat specification length incorrect"));
         }
-        seat_specs.push(calculate_seat_id(&line.to_string()));
+        // Row characters must be F or B, and column characters must be L or R
+        for (j, c) in line.char_indices() {
+            let valid = if j < 7 { c == 'F' || c == 'B' } else { c == 'L' || c == 'R' };
+            if !valid {
+                let text = &line[j..j + c.len_utf8()];
+                return Err(ParseError::at_text(5, i, raw_line, text, "bad seat character"));
+            }
+        }
+        seat_specs.push(calculate_seat_id(line));
     }
     // Sort the calculated seat IDs
     seat_specs.sort();
-    return seat_specs;
+    Ok(seat_specs)
 }
 
 #[aoc(day5, part1)]
-fn solve_part_1(seat_ids: &Vec<u64>) -> u64 {
+fn solve_part_1(seat_ids: &[u64]) -> u64 {
     // Seat IDs are already sorted, so highest seat ID is last value
-    return *seat_ids.last().unwrap();
+    *seat_ids.last().unwrap()
 }
 
 #[aoc(day5, part2)]
-fn solve_part_2(seat_ids: &Vec<u64>) -> u64 {
+fn solve_part_2(seat_ids: &[u64]) -> u64 {
     // Seat IDs are already sorted by the generator function - look for gap in seat IDs
     for i in 1..seat_ids.len() {
         let previous_id = seat_ids[i - 1];
@@ -37,47 +52,28 @@ fn solve_part_2(seat_ids: &Vec<u64>) -> u64 {
 
 /// Calculates the seat ID from the seat specification - consisting of 10 letters, the first 7 being
 /// either 'F' or 'B' and the last 3 being either 'L' or 'R'.
-fn calculate_seat_id(seat_spec: &String) -> u64 {
+fn calculate_seat_id(seat_spec: &str) -> u64 {
     let chars = seat_spec.chars().collect::<Vec<char>>();
     // Start by finding row number
     let mut r_lower = 0;
     let mut r_upper = 127;
-    for i in 0..7 {
-        if chars[i] == 'F' {
+    for &c in &chars[0..7] {
+        if c == 'F' {
             r_upper = r_lower + (r_upper - r_lower) / 2;
-        } else if chars[i] == 'B' {
+        } else if c == 'B' {
             r_lower = r_upper - (r_upper - r_lower) / 2;
         }
     }
     // Now look for column number
     let mut c_lower = 0;
     let mut c_upper = 7;
-    for i in 7..10 {
-        if chars[i] == 'L' {
+    for &c in &chars[7..10] {
+        if c == 'L' {
             c_upper = c_lower + (c_upper - c_lower) / 2;
-        } else if chars[i] == 'R' {
+        } else if c == 'R' {
             c_lower = c_upper - (c_upper - c_lower) / 2;
         }
     }
     // Now we have found the row and column number
-    return r_lower * 8 + c_lower;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d05_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day5.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(955, result);
-    }
-
-    #[test]
-    fn test_d05_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day5.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(569, result);
-    }
+    r_lower * 8 + c_lower
 }
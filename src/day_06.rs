@@ -1,9 +1,16 @@
 use std::collections::HashSet;
 
+use super::error::ParseError;
+
+/// Solution for AOC 2020 Day 6 (Custom Customs).
+pub struct Day06;
+
+impl_solution!(Day06, day = 6, input = Vec<Vec<HashSet<char>>>);
+
 #[aoc_generator(day6)]
-fn generate_input(input: &str) -> Vec<Vec<HashSet<char>>> {
+fn generate_input(input: &str) -> Result<Vec<Vec<HashSet<char>>>, ParseError> {
     let mut groups: Vec<Vec<HashSet<char>>> = vec![];
-    let mut lines = input.lines();
+    let mut lines = input.lines().enumerate();
     loop {
         let mut end_of_input = false;
         let mut group: Vec<HashSet<char>> = vec![];
@@ -15,66 +22,53 @@ fn generate_input(input: &str) -> Vec<Vec<HashSet<char>>> {
                 break;
             }
             // Check if reached end of current group, but not EOF yet
-            let line = line.unwrap().trim();
+            let (i, raw_line) = line.unwrap();
+            let line = raw_line.trim();
             if line.is_empty() {
                 break;
             }
+            // Answers are recorded as the questions (a to z) answered "yes"
+            if let Some((j, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                let text = &line[j..j + c.len_utf8()];
+                return Err(ParseError::at_text(6, i, raw_line, text, "bad answer character"));
+            }
             group.push(line.chars().collect::<HashSet<char>>());
         }
         groups.push(group);
         if end_of_input {
-            return groups;
+            return Ok(groups);
         }
     }
 }
 
 #[aoc(day6, part1)]
-fn solve_part_1(groups: &Vec<Vec<HashSet<char>>>) -> usize {
+fn solve_part_1(groups: &[Vec<HashSet<char>>]) -> usize {
     let mut total_count = 0;
     for group in groups {
         // Record the questions that at least one person in the group answered "yes" to
         let mut group_set: HashSet<char> = HashSet::new();
         for entry in group {
-            group_set = group_set.union(&entry).map(|c| *c).collect::<HashSet<char>>();
+            group_set = group_set.union(entry).copied().collect::<HashSet<char>>();
         }
         total_count += group_set.len();
     }
-    return total_count;
+    total_count
 }
 
 #[aoc(day6, part2)]
-fn solve_part_2(groups: &Vec<Vec<HashSet<char>>>) -> usize {
+fn solve_part_2(groups: &[Vec<HashSet<char>>]) -> usize {
     let mut total_count = 0; // Recount total of counts across all groups
     for group in groups {
         // Record the questions that everyone in the group answered "yes" to
         let mut overlap: HashSet<char> = HashSet::new();
-        for i in 0..group.len() {
+        for (i, answers) in group.iter().enumerate() {
             if i == 0 { // Initialise yes record on first group entry
-                overlap = overlap.union(&group[i]).map(|c| *c).collect::<HashSet<char>>();
+                overlap = overlap.union(answers).copied().collect::<HashSet<char>>();
             } else {
-                overlap = overlap.intersection(&group[i]).map(|c| *c).collect::<HashSet<char>>();
+                overlap = overlap.intersection(answers).copied().collect::<HashSet<char>>();
             }
         }
         total_count += overlap.len();
     }
-    return total_count;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d06_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day6.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(6714, result);
-    }
-
-    #[test]
-    fn test_d06_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day6.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(3435, result);
-    }
+    total_count
 }
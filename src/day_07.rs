@@ -1,16 +1,27 @@
 use std::collections::HashMap;
 
+use super::error::ParseError;
+use super::utils::search::breadth_first_search;
+
 use regex::Regex;
 
+/// Contents of each bag colour, mapping the inner bag colours to the number of those bags held.
+type BagRules = HashMap<String, HashMap<String, u64>>;
+
+/// Solution for AOC 2020 Day 7 (Handy Haversacks).
+pub struct Day07;
+
+impl_solution!(Day07, day = 7, input = BagRules);
+
 #[aoc_generator(day7)]
-fn generate_input(input: &str) -> HashMap<String, HashMap<String, u64>> {
-    let mut bag_rules: HashMap<String, HashMap<String, u64>> = HashMap::new();
+fn generate_input(input: &str) -> Result<BagRules, ParseError> {
+    let mut bag_rules: BagRules = HashMap::new();
     let bag_no_contents_regex = Regex::new(r"^(.*) bags contain no other bags.$").unwrap();
     let bag_with_contents_regex = Regex::new(r"^(.*) bags contain (.*).$").unwrap();
-    let inner_bag_regex = Regex::new(r"(\d+) (.*) bag[s]?").unwrap();
-    for line in input.lines() {
+    let inner_bag_regex = Regex::new(r"^(\d+) (.*) bag[s]?$").unwrap();
+    for (i, raw_line) in input.lines().enumerate() {
         // Trim leading and trailing whitespace, then skip empty lines.
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
@@ -25,55 +36,53 @@ fn generate_input(input: &str) -> HashMap<String, HashMap<String, u64>> {
             let captures = bag_with_contents_regex.captures(line).unwrap();
             let bag_type = captures[1].to_string();
             // Extract type and quantity for inner bags
-            for inner_bag_spec in captures[2].split(", ") {
-                let inner_bag_captures = inner_bag_regex.captures(inner_bag_spec).unwrap();
-                let quantity = inner_bag_captures[1].parse::<u64>().unwrap();
+            for inner_bag_spec in captures.get(2).unwrap().as_str().split(", ") {
+                let inner_bag_captures = match inner_bag_regex.captures(inner_bag_spec) {
+                    Some(inner_bag_captures) => inner_bag_captures,
+                    None => return Err(ParseError::at_text(
+                        7, i, raw_line, inner_bag_spec, "malformed inner bag specification"
+                    )),
+                };
+                let quantity = match inner_bag_captures[1].parse::<u64>() {
+                    Ok(quantity) => quantity,
+                    Err(_) => return Err(ParseError::at_text(
+                        7, i, raw_line, inner_bag_spec, "inner bag quantity out of range"
+                    )),
+                };
                 let inner_bag_type = inner_bag_captures[2].to_string();
                 inner_bags.insert(inner_bag_type, quantity);
             }
             bag_rules.insert(bag_type, inner_bags);
         } else {
-            panic!("Day 7 - malformed input file!");
+            return Err(ParseError::at_line(7, i, raw_line, "malformed bag rule"));
         }
     }
-    return bag_rules;
+    Ok(bag_rules)
 }
 
 #[aoc(day7, part1)]
-fn solve_part_1(bag_rules: &HashMap<String, HashMap<String, u64>>) -> u64 {
-    let mut count = 0;
-    // For each specified bag type, check if it eventually contains a "shiny gold" bag
-    for bag_type in bag_rules.keys() {
-        if check_for_inner_bag(bag_rules, bag_type, "shiny gold") {
-            count += 1;
+fn solve_part_1(bag_rules: &BagRules) -> u64 {
+    // Find the bag types that directly contain each bag type
+    let mut outer_bags: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (outer_bag, inner_bags) in bag_rules {
+        for inner_bag in inner_bags.keys() {
+            outer_bags.entry(inner_bag.as_str()).or_default().push(outer_bag.as_str());
         }
     }
-    return count;
+    // Every bag type reachable by searching outwards eventually contains a "shiny gold" bag
+    let result = breadth_first_search("shiny gold", |bag: &&str| {
+        outer_bags.get(bag).cloned().unwrap_or_default()
+    });
+    (result.len() - 1) as u64
 }
 
 #[aoc(day7, part2)]
-fn solve_part_2(bag_rules: &HashMap<String, HashMap<String, u64>>) -> u64 {
-    return count_inner_bags(bag_rules, "shiny gold");
-}
-
-/// Checks recursively if the current bag contains the target bag.
-fn check_for_inner_bag(bag_rules: &HashMap<String, HashMap<String, u64>>, current_bag: &str,
-        target_bag: &str) -> bool
-{
-    if !bag_rules.contains_key(current_bag) {
-        return false;
-    }
-    for (inner_bag, _quantity) in bag_rules.get(current_bag).unwrap() {
-        // Check if the current inner bag is the target bag or contains the target bag
-        if inner_bag == target_bag || check_for_inner_bag(bag_rules, inner_bag, target_bag) {
-            return true;
-        }
-    }
-    return false;
+fn solve_part_2(bag_rules: &BagRules) -> u64 {
+    count_inner_bags(bag_rules, "shiny gold")
 }
 
 /// Counts the number of bags contained within the current bag.
-fn count_inner_bags(bag_rules: &HashMap<String, HashMap<String, u64>>, current_bag: &str) -> u64 {
+fn count_inner_bags(bag_rules: &BagRules, current_bag: &str) -> u64 {
     let mut count = 0;
     if !bag_rules.contains_key(current_bag) {
         return count;
@@ -82,24 +91,5 @@ fn count_inner_bags(bag_rules: &HashMap<String, HashMap<String, u64>>, current_b
         // Add the number of inner bags and all bags contained within that number of inner bags
         count += quantity * (1 + count_inner_bags(bag_rules, inner_bag));
     }
-    return count;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d07_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day7.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(238, result);
-    }
-
-    #[test]
-    fn test_d07_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day7.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(82930, result);
-    }
+    count
 }
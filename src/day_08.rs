@@ -1,25 +1,39 @@
+use super::error::ParseError;
 use super::utils::machines::HandheldConsole;
 use super::utils::machines::HandheldConsoleOp;
 
+/// Solution for AOC 2020 Day 8 (Handheld Halting).
+pub struct Day08;
+
+impl_solution!(Day08, day = 8, input = Vec<HandheldConsoleOp>);
+
 #[aoc_generator(day8)]
-fn generate_input(input: &str) -> Vec<HandheldConsoleOp> {
-    let instructions = HandheldConsoleOp::parse_raw_code(input);
-    if instructions.is_none() {
-        panic!("Day 8 - malformed input file!");
+fn generate_input(input: &str) -> Result<Vec<HandheldConsoleOp>, ParseError> {
+    let mut instructions: Vec<HandheldConsoleOp> = vec![];
+    for (i, raw_line) in input.lines().enumerate() {
+        // Trim leading and trailing whitespace, then ignore empty lines
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match HandheldConsoleOp::from_string(line) {
+            Some(instruction) => instructions.push(instruction),
+            None => return Err(ParseError::at_line(8, i, raw_line, "malformed instruction")),
+        }
     }
-    return instructions.unwrap();
+    Ok(instructions)
 }
 
 #[aoc(day8, part1)]
-fn solve_part_1(instructions: &Vec<HandheldConsoleOp>) -> isize {
+fn solve_part_1(instructions: &[HandheldConsoleOp]) -> isize {
     // Execute the instructions on HandheldConsole until one would be executed for second time
     let mut handheld_console = HandheldConsole::new(instructions);
     handheld_console.execute(0, true);
-    return handheld_console.get_accumulator();
+    handheld_console.get_accumulator()
 }
 
 #[aoc(day8, part2)]
-fn solve_part_2(instructions: &Vec<HandheldConsoleOp>) -> isize {
+fn solve_part_2(instructions: &[HandheldConsoleOp]) -> isize {
     // Try increasing number of steps before number found that results in halt.
     let mut steps = 0;
     loop {
@@ -41,22 +55,3 @@ fn solve_part_2(instructions: &Vec<HandheldConsoleOp>) -> isize {
         steps += 1;
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d08_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day8.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(2058, result);
-    }
-
-    #[test]
-    fn test_d08_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day8.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(1000, result);
-    }
-}
@@ -1,16 +1,34 @@
 use std::collections::HashSet;
 use std::collections::VecDeque;
 
+use super::error::ParseError;
+
+/// Solution for AOC 2020 Day 10 (Adapter Array).
+pub struct Day10;
+
+impl_solution!(Day10, day = 10, input = Vec<u64>);
+
 #[aoc_generator(day10)]
-fn generate_input(input: &str) -> Vec<u64> {
-    let mut adapters = input.lines().map(|x| x.parse::<u64>().unwrap()).collect::<Vec<u64>>();
+fn generate_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut adapters: Vec<u64> = vec![];
+    for (i, raw_line) in input.lines().enumerate() {
+        // Trim leading and trailing whitespace, then ignore empty lines
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<u64>() {
+            Ok(joltage) => adapters.push(joltage),
+            Err(_) => return Err(ParseError::at_line(10, i, raw_line, "joltage is not a number")),
+        }
+    }
     adapters.push(0);
     adapters.sort();
-    return adapters;
+    Ok(adapters)
 }
 
 #[aoc(day10, part1)]
-fn solve_part_1(adapters: &Vec<u64>) -> u64 {
+fn solve_part_1(adapters: &[u64]) -> u64 {
     let mut total_diff_1 = 0;
     let mut total_diff_3 = 0;
     for i in 0..adapters.len() + 1 {
@@ -35,11 +53,11 @@ fn solve_part_1(adapters: &Vec<u64>) -> u64 {
             total_diff_3 += 1;
         }
     }
-    return total_diff_1 * total_diff_3;
+    total_diff_1 * total_diff_3
 }
 
 #[aoc(day10, part2)]
-fn solve_part_2(adapters: &Vec<u64>) -> u64 {
+fn solve_part_2(adapters: &[u64]) -> u64 {
     // Determine sets of adapters with joltage ratings separated by only 1 joltage
     let mut adapter_groups: VecDeque<HashSet<u64>> = VecDeque::new();
     let mut in_group = false;
@@ -72,60 +90,5 @@ fn solve_part_2(adapters: &Vec<u64>) -> u64 {
         };
         result *= count;
     }
-    return result;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d10_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day10.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(2170, result);
-    }
-
-    #[test]
-    fn test_d10_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day10.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(24803586664192, result);
-    }
-
-    #[test]
-    fn test_d10_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day10_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(35, result);
-    }
-
-    #[test]
-    fn test_d10_p2_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day10_test_001.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(8, result);
-    }
-
-    #[test]
-    fn test_d10_p1_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day10_test_002.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(220, result);
-    }
-
-    #[test]
-    fn test_d10_p2_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day10_test_002.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(19208, result);
-    }
+    result
 }
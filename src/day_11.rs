@@ -1,291 +1,244 @@
-use std::collections::HashMap;
+use std::io;
 
+use super::error::ParseError;
+use super::utils::machines::run_until_repeat;
+use super::utils::machines::CellularAutomaton;
+use super::utils::map::Aabb;
+use super::utils::map::Direction;
+use super::utils::map::Grid2D;
 use super::utils::map::Point2D;
+use super::utils::map::RayCast;
+use super::utils::render::encode_pgm;
+use super::utils::render::encode_ppm;
+use super::utils::render::FrameFormat;
+use super::utils::render::FrameWriter;
+use super::utils::render::Rgb;
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
-enum TileState {
+pub enum TileState {
     Floor,
     SeatEmpty,
     SeatOccupied
 }
 
-#[aoc_generator(day11)]
-fn generate_input(input: &str) -> HashMap<Point2D, TileState> {
-    let mut seat_locs: HashMap<Point2D, TileState> = HashMap::new();
-    let mut y = 0;
-    for line in input.lines() {
-        let mut x = 0;
-        // Remove leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
-        // Parse tiles in line
-        for tile in line.chars() {
-            let location = Point2D::new(x, y);
-            match tile {
-                '.' => {
-                    seat_locs.insert(location, TileState::Floor);
-                },
-                'L' => {
-                    seat_locs.insert(location, TileState::SeatEmpty);
-                },
-                _ => panic!("Day 11 - bad character in input file ---- {}", tile),
-            }
-            x += 1;
+impl TileState {
+    /// Converts the given character from the seat layout into the corresponding tile state.
+    fn from_char(c: char) -> Result<TileState, &'static str> {
+        match c {
+            '.' => Ok(TileState::Floor),
+            'L' => Ok(TileState::SeatEmpty),
+            '#' => Ok(TileState::SeatOccupied),
+            _ => Err("bad tile character"),
         }
-        y += 1;
     }
-    return seat_locs;
 }
 
-#[aoc(day11, part1)]
-fn solve_part_1(seating_map: &HashMap<Point2D, TileState>) -> u64 {
-    let mut seating_map = seating_map.clone();
-    loop {
-        let mut to_empty: Vec<Point2D> = vec![];
-        let mut to_occupied: Vec<Point2D> = vec![];
-        for (location, state) in seating_map.iter() {
-            match state {
-                TileState::SeatEmpty => {
-                    // Check each surrounding seat to see if none are occupied
-                    let mut toggle = true;
-                    for check_loc in location.get_surrounding_points() {
-                        if seating_map.contains_key(&check_loc) &&
-                                *seating_map.get(&check_loc).unwrap() == TileState::SeatOccupied
-                        {
-                            toggle = false;
-                            break;
-                        }
-                    }
-                    if toggle {
-                        to_occupied.push(*location);
-                    }
-                },
-                TileState::SeatOccupied => {
-                    let mut toggle = false;
-                    let mut count = 0;
-                    for check_loc in location.get_surrounding_points() {
-                        if seating_map.contains_key(&check_loc) &&
-                                *seating_map.get(&check_loc).unwrap() == TileState::SeatOccupied
-                        {
-                            count += 1;
-                        }
-                        if count >= 4 {
-                            toggle = true;
-                            break;
-                        }
-                    }
-                    if toggle {
-                        to_empty.push(*location);
-                    }
-                },
-                TileState::Floor => (),
-            }
-        }
-        // Check if state stayed stable
-        if to_empty.is_empty() && to_occupied.is_empty() {
-            let mut count = 0;
-            for (_loc, state) in seating_map.iter() {
-                if *state == TileState::SeatOccupied {
-                    count += 1;
-                }
-            }
-            return count;
-        }
-        // Toggle seats as needed
-        for loc in to_empty {
-            seating_map.insert(loc, TileState::SeatEmpty);
-        }
-        for loc in to_occupied {
-            seating_map.insert(loc, TileState::SeatOccupied);
+impl From<TileState> for char {
+    fn from(state: TileState) -> char {
+        match state {
+            TileState::Floor => '.',
+            TileState::SeatEmpty => 'L',
+            TileState::SeatOccupied => '#',
         }
     }
 }
 
-#[aoc(day11, part2)]
-fn solve_part_2(seating_map: &HashMap<Point2D, TileState>) -> u64 {
-    let mut seating_map = seating_map.clone();
-    let max_x = seating_map.keys().map(|loc| loc.get_x()).max().unwrap();
-    let max_y = seating_map.keys().map(|loc| loc.get_y()).max().unwrap();
-    loop {
-        let mut to_empty: Vec<Point2D> = vec![];
-        let mut to_occupied: Vec<Point2D> = vec![];
-        for (location, state) in seating_map.iter() {
-            match state {
-                TileState::SeatEmpty => {
-                    // Check each surrounding seat to see if none are occupied
-                    let mut toggle = true;
-                    for check_loc in get_first_visible_points(&seating_map, location, max_x, max_y) {
-                        if seating_map.contains_key(&check_loc) &&
-                                *seating_map.get(&check_loc).unwrap() == TileState::SeatOccupied
-                        {
-                            toggle = false;
-                            break;
-                        }
-                    }
-                    if toggle {
-                        to_occupied.push(*location);
-                    }
-                },
-                TileState::SeatOccupied => {
-                    let mut toggle = false;
-                    let mut count = 0;
-                    for check_loc in get_first_visible_points(&seating_map, location, max_x, max_y) {
-                        if seating_map.contains_key(&check_loc) &&
-                                *seating_map.get(&check_loc).unwrap() == TileState::SeatOccupied
-                        {
-                            count += 1;
-                        }
-                        if count >= 5 {
-                            toggle = true;
-                            break;
-                        }
-                    }
-                    if toggle {
-                        to_empty.push(*location);
-                    }
-                },
-                TileState::Floor => (),
-            }
-        }
-        // Check if state stayed stable
-        if to_empty.is_empty() && to_occupied.is_empty() {
-            let mut count = 0;
-            for (_loc, state) in seating_map.iter() {
-                if *state == TileState::SeatOccupied {
-                    count += 1;
-                }
-            }
-            return count;
-        }
-        // Toggle seats as needed
-        for loc in to_empty {
-            seating_map.insert(loc, TileState::SeatEmpty);
-        }
-        for loc in to_occupied {
-            seating_map.insert(loc, TileState::SeatOccupied);
+/// Rule used to decide which seats are considered when people choose whether to sit in or leave
+/// a seat.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum SeatingRule {
+    /// The (up to) eight seats adjacent to the seat are considered.
+    Adjacent,
+    /// The first seat visible in each of the eight directions from the seat is considered.
+    LineOfSight,
+}
+
+impl SeatingRule {
+    /// Gets the number of occupied seats considered at (or above) which people leave a seat.
+    fn get_tolerance(&self) -> usize {
+        match self {
+            SeatingRule::Adjacent => 4,
+            SeatingRule::LineOfSight => 5,
         }
     }
 }
 
-fn get_first_visible_points(seating_map: &HashMap<Point2D, TileState>, location: &Point2D,
-        max_x: i64, max_y: i64) -> Vec<Point2D>
-{
-    let mut output: Vec<Point2D> = vec![];
-    // Up
-    let mut check_point = *location;
-    loop {
-        if check_point.get_y() < 0 {
-            break;
-        }
-        check_point.move_point(0, -1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
+/// Represents the seating system of the waiting area, with people choosing whether to sit in or
+/// leave each seat in rounds according to the seating rule.
+#[derive(Clone)]
+struct SeatingSystem {
+    seating_map: Grid2D<TileState>,
+    rule: SeatingRule,
+    rounds: u64,
+    changed_region: Option<Aabb<2>>,
+}
+
+impl SeatingSystem {
+    /// Creates a new seating system with the given seating map and rule.
+    fn new(seating_map: &Grid2D<TileState>, rule: SeatingRule) -> Self {
+        // Every seat may change in the first round
+        let changed_region = Aabb::from_points(seating_map.iter_points());
+        Self {
+            seating_map: seating_map.clone(),
+            rule,
+            rounds: 0,
+            changed_region,
         }
     }
-    // Diag - up right
-    let mut check_point = *location;
-    loop {
-        if check_point.get_x() > max_x || check_point.get_y() < 0 {
-            break;
-        }
-        check_point.move_point(1, -1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
+
+    /// Gets the region containing every seat that may change state in the next round, or None if
+    /// no seats can change. Under the adjacent rule, a seat can only change if it or an adjacent
+    /// seat changed in the last round. Under the line of sight rule, the first seat visible from a
+    /// seat can be anywhere on the seating map, so every seat is considered if any seat changed.
+    fn get_candidate_region(&self) -> Option<Aabb<2>> {
+        let changed_region = self.changed_region?;
+        match self.rule {
+            SeatingRule::Adjacent => Some(changed_region.expand(1)),
+            SeatingRule::LineOfSight => Aabb::from_points(self.seating_map.iter_points()),
         }
     }
-    // Right
-    let mut check_point = *location;
-    loop {
-        if check_point.get_x() > max_x {
-            break;
-        }
-        check_point.move_point(1, 0);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
+}
+
+impl CellularAutomaton for SeatingSystem {
+    type State = Grid2D<TileState>;
+
+    /// Conducts a single round of seating changes, with every seat changing state simultaneously.
+    fn conduct_step(&mut self) {
+        let directions = Direction::get_all()
+            .iter()
+            .map(|direction| direction.get_delta())
+            .collect::<Vec<Point2D>>();
+        let ray_cast = RayCast::new();
+        let is_seat = |_: &Point2D, state: &TileState| *state != TileState::Floor;
+        let seating_map = &self.seating_map;
+        let is_occupied = |loc: &Point2D| seating_map.get(loc) == Some(&TileState::SeatOccupied);
+        let mut next_seating_map = seating_map.clone();
+        let mut changed_seats: Vec<Point2D> = vec![];
+        let candidate_region = self.get_candidate_region();
+        for location in candidate_region.iter().flatten() {
+            let state = match seating_map.get(&location) {
+                Some(TileState::Floor) | None => continue,
+                Some(state) => state,
+            };
+            // Count the occupied seats surrounding or first visible from the current seat
+            let count = match self.rule {
+                SeatingRule::Adjacent => {
+                    location.iter_moore_neighbours().filter(|loc| is_occupied(loc)).count()
+                },
+                SeatingRule::LineOfSight => ray_cast
+                    .cast_all(seating_map, &location, &directions, is_seat)
+                    .iter()
+                    .flatten()
+                    .filter(|loc| is_occupied(loc))
+                    .count(),
+            };
+            if *state == TileState::SeatEmpty && count == 0 {
+                next_seating_map.set(&location, TileState::SeatOccupied);
+                changed_seats.push(location);
+            } else if *state == TileState::SeatOccupied && count >= self.rule.get_tolerance() {
+                next_seating_map.set(&location, TileState::SeatEmpty);
+                changed_seats.push(location);
+            }
         }
+        self.seating_map = next_seating_map;
+        self.changed_region = Aabb::from_points(changed_seats);
+        self.rounds += 1;
     }
-    // Diag - down right
-    let mut check_point = *location;
-    loop {
-        if check_point.get_x() > max_x || check_point.get_y() > max_y {
-            break;
-        }
-        check_point.move_point(1, 1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
-        }
+
+    fn get_steps(&self) -> u64 {
+        self.rounds
     }
-    // Down
-    let mut check_point = *location;
-    loop {
-        if check_point.get_y() > max_y {
-            break;
-        }
-        check_point.move_point(0, 1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
-        }
+
+    /// Gets the number of occupied seats.
+    fn get_population(&self) -> u64 {
+        self.seating_map.count_matching(|state| *state == TileState::SeatOccupied) as u64
     }
-    // Diag - down left
-    let mut check_point = *location;
-    loop {
-        if check_point.get_x() < 0 || check_point.get_y() > max_y  {
-            break;
-        }
-        check_point.move_point(-1, 1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
-        }
+
+    fn get_state(&self) -> Self::State {
+        self.seating_map.clone()
     }
-    // Left
-    let mut check_point = *location;
+}
+
+/// Number of pixels along each side of the square drawn for each tile in image frames.
+const FRAME_SCALE: usize = 4;
+/// Maximum number of rounds of seating changes conducted while waiting for the seating map to
+/// become stable.
+const MAX_ROUNDS: u64 = 10000;
+
+/// Solution for AOC 2020 Day 11 (Seating System).
+pub struct Day11;
+
+impl_solution!(Day11, day = 11, input = Grid2D<TileState>);
+
+#[aoc_generator(day11)]
+fn generate_input(input: &str) -> Result<Grid2D<TileState>, ParseError> {
+    Grid2D::from_str_with(input, TileState::from_char).map_err(|e| e.into_parse_error(11))
+}
+
+#[aoc(day11, part1)]
+fn solve_part_1(seating_map: &Grid2D<TileState>) -> usize {
+    find_stable_occupied_seats(seating_map, SeatingRule::Adjacent)
+}
+
+#[aoc(day11, part2)]
+fn solve_part_2(seating_map: &Grid2D<TileState>) -> usize {
+    find_stable_occupied_seats(seating_map, SeatingRule::LineOfSight)
+}
+
+/// Writes a frame showing the seating map in the given format for the initial state and after each
+/// round of seating changes, until the seating map is stable. Part 1 considers the seats adjacent
+/// to each seat, with part 2 considering the first seat visible in each direction.
+pub fn write_frames(seating_map: &Grid2D<TileState>, part: u32, writer: &mut FrameWriter)
+        -> io::Result<()>
+{
+    let rule = match part {
+        1 => SeatingRule::Adjacent,
+        2 => SeatingRule::LineOfSight,
+        _ => panic!("Day 11 - bad part number: {}", part),
+    };
+    let mut seating_system = SeatingSystem::new(seating_map, rule);
+    let stable_round = find_stable_round(&mut seating_system.clone());
     loop {
-        if check_point.get_x() < 0 {
-            break;
-        }
-        check_point.move_point(-1, 0);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
+        let seating_map = &seating_system.seating_map;
+        let frame = match writer.get_format() {
+            FrameFormat::Ascii => format!("{}\n", seating_map).into_bytes(),
+            FrameFormat::Ppm => encode_ppm(seating_map, FRAME_SCALE, |state| match state {
+                TileState::Floor => Rgb::grey(0),
+                TileState::SeatEmpty => Rgb::new(64, 96, 160),
+                TileState::SeatOccupied => Rgb::new(255, 208, 64),
+            }),
+            FrameFormat::Pgm => encode_pgm(seating_map, FRAME_SCALE, |state| match state {
+                TileState::Floor => 0,
+                TileState::SeatEmpty => 96,
+                TileState::SeatOccupied => 255,
+            }),
+        };
+        writer.write_frame(&frame)?;
+        if seating_system.get_steps() == stable_round {
+            return Ok(());
         }
+        seating_system.conduct_step();
     }
-    // Diag - up left
-    let mut check_point = *location;
-    loop {
-        if check_point.get_y() < 0 || check_point.get_x() < 0 {
-            break;
-        }
-        check_point.move_point(-1, -1);
-        if seating_map.contains_key(&check_point) &&
-                *seating_map.get(&check_point).unwrap() != TileState::Floor
-        {
-            output.push(check_point);
-            break;
-        }
+}
+
+/// Conducts rounds of seating changes under the given rule until the seating map is stable,
+/// returning the number of occupied seats once stable.
+fn find_stable_occupied_seats(seating_map: &Grid2D<TileState>, rule: SeatingRule) -> usize {
+    let mut seating_system = SeatingSystem::new(seating_map, rule);
+    find_stable_round(&mut seating_system);
+    seating_system.get_population() as usize
+}
+
+/// Conducts rounds of seating changes until the seating map is stable, returning the round at
+/// which the seating map stopped changing.
+///
+/// Panics if the seating map does not become stable within the maximum number of rounds.
+fn find_stable_round(seating_system: &mut SeatingSystem) -> u64 {
+    let history = run_until_repeat(seating_system, MAX_ROUNDS);
+    if !history.is_fixed_point() {
+        panic!("Day 11 - seating map did not become stable within {} rounds", MAX_ROUNDS);
     }
-    return output;
+    history.get_cycle_start().unwrap()
 }
 
 #[cfg(test)]
@@ -293,16 +246,42 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_d11_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day11.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(2476, result);
+    fn test_d11_p1_001_history() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day11_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        let mut seating_system = SeatingSystem::new(&input, SeatingRule::Adjacent);
+        let history = run_until_repeat(&mut seating_system, MAX_ROUNDS);
+        assert!(history.is_fixed_point());
+        assert_eq!(Some(5), history.get_cycle_start());
+        assert_eq!(&vec![0, 71, 20, 51, 30, 37, 37], history.get_populations());
+        assert_eq!(Some(37), history.get_population(100));
+    }
+
+    #[test]
+    fn test_d11_p2_001_history() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day11_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        let mut seating_system = SeatingSystem::new(&input, SeatingRule::LineOfSight);
+        let history = run_until_repeat(&mut seating_system, MAX_ROUNDS);
+        assert!(history.is_fixed_point());
+        assert_eq!(Some(6), history.get_cycle_start());
+        assert_eq!(26, history.get_final_population());
     }
 
     #[test]
-    fn test_d11_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day11.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(2257, result);
+    fn test_d11_p1_001_step_limit() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day11_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        let mut seating_system = SeatingSystem::new(&input, SeatingRule::Adjacent);
+        let history = run_until_repeat(&mut seating_system, 3);
+        assert_eq!(None, history.get_period());
+        assert_eq!(3, history.get_last_step());
+        assert_eq!(None, history.get_population(4));
     }
 }
@@ -1,10 +1,13 @@
-use super::utils::map::CardinalDirection;
+use super::error::ParseError;
+use super::utils::map::Direction;
+use super::utils::map::Navigator;
+use super::utils::map::NavigatorAction;
 use super::utils::map::Point2D;
 
 use regex::Regex;
 
 #[derive(Copy, Clone, Debug)]
-enum Action {
+pub enum Action {
     North,
     South,
     East,
@@ -17,98 +20,92 @@ enum Action {
 impl Action {
     fn from_string(input: &str) -> Option<Action> {
         match input {
-            "N" => return Some(Action::North),
-            "S" => return Some(Action::South),
-            "E" => return Some(Action::East),
-            "W" => return Some(Action::West),
-            "L" => return Some(Action::Left),
-            "R" => return Some(Action::Right),
-            "F" => return Some(Action::Forward),
-            _ => return None,
+            "N" => Some(Action::North),
+            "S" => Some(Action::South),
+            "E" => Some(Action::East),
+            "W" => Some(Action::West),
+            "L" => Some(Action::Left),
+            "R" => Some(Action::Right),
+            "F" => Some(Action::Forward),
+            _ => None,
+        }
+    }
+
+    /// Converts the action with the given value into the equivalent navigator action.
+    fn to_navigator_action(self, value: i64) -> NavigatorAction {
+        match self {
+            Action::North => NavigatorAction::Move(Direction::North, value),
+            Action::South => NavigatorAction::Move(Direction::South, value),
+            Action::East => NavigatorAction::Move(Direction::East, value),
+            Action::West => NavigatorAction::Move(Direction::West, value),
+            Action::Left => NavigatorAction::TurnLeft(value),
+            Action::Right => NavigatorAction::TurnRight(value),
+            Action::Forward => NavigatorAction::Forward(value),
         }
     }
 }
 
+/// Solution for AOC 2020 Day 12 (Rain Risk).
+pub struct Day12;
+
+impl_solution!(Day12, day = 12, input = Vec<(Action, i64)>);
+
 #[aoc_generator(day12)]
-fn generate_input(input: &str) -> Vec<(Action, i64)> {
+fn generate_input(input: &str) -> Result<Vec<(Action, i64)>, ParseError> {
     let mut instructions: Vec<(Action, i64)> = vec![];
     let line_regex = Regex::new(r"^(N|S|E|W|L|R|F)(\d+)$").unwrap();
-    for line in input.lines() {
-        let line = line.trim();
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
-        if line_regex.is_match(line) {
-            let captures = line_regex.captures(line).unwrap();
-            let action = Action::from_string(&captures[1]).unwrap();
-            let value = captures[2].parse::<i64>().unwrap();
-            instructions.push((action, value));
+        let captures = match line_regex.captures(line) {
+            Some(captures) => captures,
+            None => return Err(ParseError::at_line(
+                12, i, raw_line, "malformed navigation instruction"
+            )),
+        };
+        let action = Action::from_string(&captures[1]).unwrap();
+        let value = match captures[2].parse::<i64>() {
+            Ok(value) => value,
+            Err(_) => {
+                let text = captures.get(2).unwrap().as_str();
+                return Err(ParseError::at_text(12, i, raw_line, text, "action value out of range"));
+            }
+        };
+        // The ship can only face the four cardinal directions
+        if matches!(action, Action::Left | Action::Right) && value % 90 != 0 {
+            let text = captures.get(2).unwrap().as_str();
+            return Err(ParseError::at_text(12, i, raw_line, text, "turn not a multiple of 90"));
         }
+        instructions.push((action, value));
     }
-    return instructions;
+    Ok(instructions)
 }
 
 #[aoc(day12, part1)]
-fn solve_part_1(instructions: &Vec<(Action, i64)>) -> u64 {
-    // Initialise ship direction and position
-    let mut ship_dir = CardinalDirection::East;
-    let mut ship_loc = Point2D::new(0, 0);
-    for (action, value) in instructions {
-        let value = *value;
-        match action {
-            Action::North => ship_loc.move_point(0, -value),
-            Action::South => ship_loc.move_point(0, value),
-            Action::East => ship_loc.move_point(value, 0),
-            Action::West => ship_loc.move_point(-value, 0),
-            Action::Left => ship_dir = ship_dir.rotate_left(value as u64),
-            Action::Right => ship_dir = ship_dir.rotate_right(value as u64),
-            Action::Forward => match ship_dir {
-                CardinalDirection::North => ship_loc.move_point(0, -value),
-                CardinalDirection::South => ship_loc.move_point(0, value),
-                CardinalDirection::East => ship_loc.move_point(value, 0),
-                CardinalDirection::West => ship_loc.move_point(-value, 0),
-            },
-        }
-    }
-    return ship_loc.calculate_manhattan_distance(&Point2D::new(0, 0));
+fn solve_part_1(instructions: &[(Action, i64)]) -> u64 {
+    let navigator = Navigator::with_heading(Point2D::new(0, 0), Direction::East);
+    navigate_ship(navigator, instructions)
 }
 
 #[aoc(day12, part2)]
-fn solve_part_2(instructions: &Vec<(Action, i64)>) -> u64 {
-    // Initialise ship direction, location and waypoint
-    let mut ship_loc = Point2D::new(0, 0);
-    let mut waypoint = Point2D::new(10, -1); // record waypoint as delta from ship location
-    for (action, value) in instructions.iter() {
-        let value = *value;
-        match action {
-            Action::North => waypoint.move_point(0, -value),
-            Action::South => waypoint.move_point(0, value),
-            Action::East => waypoint.move_point(value, 0),
-            Action::West => waypoint.move_point(-value, 0),
-            Action::Left => {
-                let rotations = value / 90;
-                for _ in 0..rotations {
-                    let new_x = waypoint.get_y();
-                    let new_y = -waypoint.get_x();
-                    waypoint = Point2D::new(new_x, new_y);
-                }
-            },
-            Action::Right => {
-                let rotations = value / 90;
-                for _ in 0..rotations {
-                    let new_x = -waypoint.get_y();
-                    let new_y = waypoint.get_x();
-                    waypoint = Point2D::new(new_x, new_y);
-                }
-            },
-            Action::Forward => {
-                for _ in 0..value {
-                    ship_loc.move_point(waypoint.get_x(), waypoint.get_y());
-                }
-            }
+fn solve_part_2(instructions: &[(Action, i64)]) -> u64 {
+    // Waypoint is recorded as delta from ship location
+    let navigator = Navigator::with_waypoint(Point2D::new(0, 0), Point2D::new(10, -1));
+    navigate_ship(navigator, instructions)
+}
+
+/// Conducts the navigation instructions using the given navigator, returning the Manhattan distance
+/// between the starting and final locations of the ship.
+fn navigate_ship(mut navigator: Navigator, instructions: &[(Action, i64)]) -> u64 {
+    let start_loc = navigator.get_location();
+    for (action, value) in instructions {
+        if let Err(e) = navigator.apply(&action.to_navigator_action(*value)) {
+            panic!("Day 12 - bad ship rotation: {}", e);
         }
     }
-    return ship_loc.calculate_manhattan_distance(&Point2D::new(0, 0));
+    navigator.get_location().calculate_manhattan_distance(&start_loc)
 }
 
 #[cfg(test)]
@@ -116,16 +113,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_d12_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day12.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(2280, result);
-    }
-
-    #[test]
-    fn test_d12_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day12.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(38693, result);
+    fn test_d12_parse_error_turn() {
+        let error = generate_input("F10\nR45\nF10\n").unwrap_err();
+        assert_eq!(12, error.get_day());
+        assert_eq!(2, error.get_line());
+        assert_eq!(2, error.get_column());
+        assert_eq!("45", error.get_text());
+        let error = generate_input("L270\nL100\n").unwrap_err();
+        assert_eq!(2, error.get_line());
+        assert_eq!("100", error.get_text());
     }
 }
@@ -1,3 +1,4 @@
+use super::error::ParseError;
 use super::utils::math::ntheory::*;
 
 struct BusTimer {
@@ -8,13 +9,13 @@ struct BusTimer {
 impl BusTimer {
     pub fn new(bus_id: i64, offset: i64) -> Self {
         Self {
-            bus_id: bus_id,
-            offset: offset,
+            bus_id,
+            offset,
         }
     }
 
     pub fn get_bus_id(&self) -> i64 {
-        return self.bus_id;
+        self.bus_id
     }
 
     pub fn calculate_next_arrival_after_timestamp(&mut self, timestamp: i64) -> i64 {
@@ -26,25 +27,37 @@ impl BusTimer {
             }
         };
         let rounds = diff / self.bus_id;
-        let next_timestamp = self.bus_id * (rounds + 1);
-        return next_timestamp;
+        
+        self.bus_id * (rounds + 1)
     }
 }
 
+/// Solution for AOC 2020 Day 13 (Shuttle Search).
+pub struct Day13;
+
+impl_solution!(Day13, day = 13, input = (i64, Vec<(i64, i64)>));
+
 #[aoc_generator(day13)]
-fn generate_input(input: &str) -> (i64, Vec<(i64, i64)>) {
+fn generate_input(input: &str) -> Result<(i64, Vec<(i64, i64)>), ParseError> {
     let lines = input.lines().collect::<Vec<&str>>();
+    if lines.len() < 2 {
+        return Err(ParseError::at_end(13, input, "expected timestamp and bus schedule lines"));
+    }
     // Get earliest time for catching bus
-    let earliest_timestamp = lines[0].parse::<i64>().unwrap();
-    let mut i = 0;
+    let earliest_timestamp = match lines[0].trim().parse::<i64>() {
+        Ok(timestamp) => timestamp,
+        Err(_) => return Err(ParseError::at_line(13, 0, lines[0], "timestamp is not a number")),
+    };
     let mut bus_slots: Vec<(i64, i64)> = vec![];
-    for id in lines[1].split(",") {
+    for (i, id) in lines[1].trim().split(",").enumerate() {
         if id != "x" {
-            bus_slots.push((i, id.parse::<i64>().unwrap()));
+            match id.parse::<i64>() {
+                Ok(id) => bus_slots.push((i as i64, id)),
+                Err(_) => return Err(ParseError::at_text(13, 1, lines[1], id, "bad bus ID")),
+            }
         }
-        i += 1;
     }
-    return (earliest_timestamp, bus_slots);
+    Ok((earliest_timestamp, bus_slots))
 }
 
 #[aoc(day13, part1)]
@@ -66,7 +79,7 @@ fn solve_part_1(input: &(i64, Vec<(i64, i64)>)) -> i64 {
             bus_id_result = bus_timer.get_bus_id();
         }
     }
-    return (timestamp_result - earliest_timestamp) * bus_id_result;
+    (timestamp_result - earliest_timestamp) * bus_id_result
 }
 
 #[aoc(day13, part2)]
@@ -75,91 +88,8 @@ fn solve_part_2(input: &(i64, Vec<(i64, i64)>)) -> i64 {
     let bus_ids = input.1.iter().map(|x| x.1).collect::<Vec<i64>>();
     // Determine offsets as applicable to the earliest timestamp being searched for
     let offsets = input.1.iter().map(|x| (x.1 - x.0) % x.1).collect::<Vec<i64>>();
-    let result = solve_crt(&offsets, &bus_ids);
-    if result.is_some() {
-        return result.unwrap();
+    if let Some(result) = solve_crt(&offsets, &bus_ids) {
+        return result;
     }
     panic!("Day 13 Part 2 - did not find valid solution!");
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d13_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day13.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(1835, result);
-    }
-
-    #[test]
-    fn test_d13_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day13.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(247086664214628, result);
-    }
-
-    #[test]
-    fn test_d13_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(295, result);
-    }
-
-    #[test]
-    fn test_d13_p2_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_001.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(1068781, result);
-    }
-
-    #[test]
-    fn test_d13_p2_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_002.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(3417, result);
-    }
-
-    #[test]
-    fn test_d13_p2_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_003.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(754018, result);
-    }
-
-    #[test]
-    fn test_d13_p2_004() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_004.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(779210, result);
-    }
-
-    #[test]
-    fn test_d13_p2_005() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_005.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(1261476, result);
-    }
-
-    #[test]
-    fn test_d13_p2_006() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day13_test_006.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(1202161486, result);
-    }
-}
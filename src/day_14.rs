@@ -1,14 +1,16 @@
 use std::collections::HashMap;
 
+use super::error::ParseError;
+
 use regex::Regex;
 
-enum Operation {
+pub enum Operation {
     SetMask{mask_bits: Vec<(u64, BitmaskBit)>},
     SetMemory{index: u64, value: u64}
 }
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
-enum BitmaskBit {
+pub enum BitmaskBit {
     Bit0,
     Bit1,
     BitX
@@ -17,23 +19,28 @@ enum BitmaskBit {
 impl BitmaskBit {
     pub fn from_char(input: char) -> Option<BitmaskBit> {
         match input {
-            '0' => return Some(BitmaskBit::Bit0),
-            '1' => return Some(BitmaskBit::Bit1),
-            'X' => return Some(BitmaskBit::BitX),
-            _ => return None,
+            '0' => Some(BitmaskBit::Bit0),
+            '1' => Some(BitmaskBit::Bit1),
+            'X' => Some(BitmaskBit::BitX),
+            _ => None,
         }
     }
 }
 
+/// Solution for AOC 2020 Day 14 (Docking Data).
+pub struct Day14;
+
+impl_solution!(Day14, day = 14, input = Vec<Operation>);
+
 #[aoc_generator(day14)]
-fn generate_input(input: &str) -> Vec<Operation> {
+fn generate_input(input: &str) -> Result<Vec<Operation>, ParseError> {
     // Create regexes to match mask and memory operation lines
     let mask_regex = Regex::new(r"^mask = ((X|1|0){36})$").unwrap();
     let mem_regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
     let mut operations: Vec<Operation> = vec![];
-    for line in input.lines() {
+    for (i, raw_line) in input.lines().enumerate() {
         // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
@@ -42,29 +49,41 @@ fn generate_input(input: &str) -> Vec<Operation> {
             let mut mask_bits: Vec<(u64, BitmaskBit)> = vec![];
             let captures = mask_regex.captures(line).unwrap();
             let mask_chars = captures[1].chars().collect::<Vec<char>>();
-            for i in 0..36 {
+            for (i, &c) in mask_chars.iter().enumerate() {
                 let place = (35 - i) as u64;
-                let bitmask_bit = BitmaskBit::from_char(mask_chars[i]).unwrap();
+                let bitmask_bit = BitmaskBit::from_char(c).unwrap();
                 mask_bits.push((place, bitmask_bit));
             }
-            let operation = Operation::SetMask{mask_bits: mask_bits};
+            let operation = Operation::SetMask{mask_bits};
             operations.push(operation);
         } else if mem_regex.is_match(line) {
             let captures = mem_regex.captures(line).unwrap();
-            let index = captures[1].parse::<u64>().unwrap();
-            let value = captures[2].parse::<u64>().unwrap();
-            let operation = Operation::SetMemory{index: index, value: value};
+            let index = captures.get(1).unwrap().as_str();
+            let value = captures.get(2).unwrap().as_str();
+            let index = match index.parse::<u64>() {
+                Ok(index) => index,
+                Err(_) => return Err(ParseError::at_text(
+                    14, i, raw_line, index, "bad memory address"
+                )),
+            };
+            let value = match value.parse::<u64>() {
+                Ok(value) => value,
+                Err(_) => return Err(ParseError::at_text(
+                    14, i, raw_line, value, "bad memory value"
+                )),
+            };
+            let operation = Operation::SetMemory{index, value};
             operations.push(operation);
         } else {
-            panic!("Day 14 - malformed input file!");
+            return Err(ParseError::at_line(14, i, raw_line, "malformed operation"));
         }
     }
-    return operations;
+    Ok(operations)
 }
 
 /// Applies the given bitmask to the provided value using the Version 1 rules specified in AOC 2020
 /// Day 14 Part 1.
-fn apply_bitmask_version_1(value: u64, bitmask: &Vec<(u64, BitmaskBit)>) -> u64 {
+fn apply_bitmask_version_1(value: u64, bitmask: &[(u64, BitmaskBit)]) -> u64 {
     let mut new_value = value;
     for (index, bitmask_bit) in bitmask.iter() {
         match bitmask_bit {
@@ -73,25 +92,25 @@ fn apply_bitmask_version_1(value: u64, bitmask: &Vec<(u64, BitmaskBit)>) -> u64
                 new_value &= mask;
             },
             BitmaskBit::Bit1 => { // Set bit at current index
-                let mask = 0 ^ (1 << index);
+                let mask = 1 << index;
                 new_value |= mask;
             },
             BitmaskBit::BitX => (),
         }
     }
-    return new_value;
+    new_value
 }
 
 /// Applies the given bitmask to the provided memory address using the Version 2 rules to generate
 /// an array of modified addresses.
-fn apply_bitmask_version_2(address: u64, bitmask: &Vec<(u64, BitmaskBit)>) -> Vec<u64> {
+fn apply_bitmask_version_2(address: u64, bitmask: &[(u64, BitmaskBit)]) -> Vec<u64> {
     let mut new_address = address;
     // First set any bits that need to be set
     for (index, bitmask_bit) in bitmask.iter() {
         match bitmask_bit {
             BitmaskBit::Bit0 => (),
             BitmaskBit::Bit1 => {
-                let mask = 0 ^ (1 << index);
+                let mask = 1 << index;
                 new_address |= mask;
             },
             BitmaskBit::BitX => (),
@@ -101,7 +120,7 @@ fn apply_bitmask_version_2(address: u64, bitmask: &Vec<(u64, BitmaskBit)>) -> Ve
     let mut floating_bits = bitmask.to_vec();
     floating_bits.retain(|x| x.1 == BitmaskBit::BitX);
     // Generate bit sequences and add results to output
-    let cap = (2 as u64).pow(floating_bits.len() as u32);
+    let cap = 2_u64.pow(floating_bits.len() as u32);
     let mut modified_addresses: Vec<u64> = vec![];
     for val in 0..cap {
         // Process each bit in the current bit sequence
@@ -112,18 +131,18 @@ fn apply_bitmask_version_2(address: u64, bitmask: &Vec<(u64, BitmaskBit)>) -> Ve
                 let mask = u64::MAX ^ (1 << floating_bits[i].0);
                 modified_address &= mask;
             } else { // bit_value == 1
-                let mask = 0 ^ (1 << floating_bits[i].0);
+                let mask = 1 << floating_bits[i].0;
                 modified_address |= mask;
             }
         }
         modified_addresses.push(modified_address);
     }
     // Got the new address after applying the bitmask
-    return modified_addresses;
+    modified_addresses
 }
 
 #[aoc(day14, part1)]
-fn solve_part_1(operations: &Vec<Operation>) -> u64 {
+fn solve_part_1(operations: &[Operation]) -> u64 {
     let mut port_comp_memory: HashMap<u64, u64> = HashMap::new();
     let mut current_bitmask: &Vec<(u64, BitmaskBit)> = &vec![];
     // Process all operations, being set mask or set memory operations
@@ -140,14 +159,14 @@ fn solve_part_1(operations: &Vec<Operation>) -> u64 {
         }
     }
     // Add all values in memory that are NEQ 0
-    let mut values = port_comp_memory.values().map(|x| *x).collect::<Vec<u64>>();
+    let mut values = port_comp_memory.values().copied().collect::<Vec<u64>>();
     values.retain(|x| *x != 0);
     let sum = values.iter().sum();
-    return sum;
+    sum
 }
 
 #[aoc(day14, part2)]
-fn solve_part_2(operations: &Vec<Operation>) -> u64 {
+fn solve_part_2(operations: &[Operation]) -> u64 {
     let mut port_comp_memory: HashMap<u64, u64> = HashMap::new();
     let mut current_bitmask: &Vec<(u64, BitmaskBit)> = &vec![];
     // Process all operations, being set mask or set memory operations
@@ -165,45 +184,8 @@ fn solve_part_2(operations: &Vec<Operation>) -> u64 {
         }
     }
     // Add all values in memory that are NEQ 0
-    let mut values = port_comp_memory.values().map(|x| *x).collect::<Vec<u64>>();
+    let mut values = port_comp_memory.values().copied().collect::<Vec<u64>>();
     values.retain(|x| *x != 0);
     let sum = values.iter().sum();
-    return sum;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d14_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day14.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(17481577045893, result);
-    }
-
-    #[test]
-    fn test_d14_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day14.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(4160009892257, result);
-    }
-
-    #[test]
-    fn test_d14_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day14_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(165, result);
-    }
-
-    #[test]
-    fn test_d13_p2_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day14_test_002.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(208, result);
-    }
+    sum
 }
@@ -1,24 +1,44 @@
 use std::collections::HashMap;
 
+use super::error::ParseError;
+
+/// Solution for AOC 2020 Day 15 (Rambunctious Recitation).
+pub struct Day15;
+
+impl_solution!(Day15, day = 15, input = Vec<u64>);
+
 #[aoc_generator(day15)]
-fn generate_input(input: &str) -> Vec<u64> {
-    return input.trim().split(",").map(|x| x.parse::<u64>().unwrap()).collect::<Vec<u64>>();
+fn generate_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut starting_numbers: Vec<u64> = vec![];
+    let (i, raw_line) = match input.lines().enumerate().find(|(_, x)| !x.trim().is_empty()) {
+        Some(first_line) => first_line,
+        None => return Err(ParseError::at_end(15, input, "expected starting numbers")),
+    };
+    for value in raw_line.trim().split(",") {
+        match value.parse::<u64>() {
+            Ok(value) => starting_numbers.push(value),
+            Err(_) => return Err(ParseError::at_text(
+                15, i, raw_line, value, "bad starting number"
+            )),
+        }
+    }
+    Ok(starting_numbers)
 }
 
 #[aoc(day15, part1)]
-fn solve_part_1(starting_numbers: &Vec<u64>) -> u64 {
-    return play_memory_game(2020, starting_numbers);
+fn solve_part_1(starting_numbers: &[u64]) -> u64 {
+    play_memory_game(2020, starting_numbers)
 }
 
 #[aoc(day15, part2)]
-fn solve_part_2(starting_numbers: &Vec<u64>) -> u64 {
-    return play_memory_game(30000000, starting_numbers);
+fn solve_part_2(starting_numbers: &[u64]) -> u64 {
+    play_memory_game(30000000, starting_numbers)
 }
 
 /// Plays the Elves' memory game for the specified number of turns using the given starting numbers.
 /// 
 /// Rules as described in AOC 2020 Day 15.
-fn play_memory_game(num_turns: usize, starting_numbers: &Vec<u64>) -> u64 {
+fn play_memory_game(num_turns: usize, starting_numbers: &[u64]) -> u64 {
     // Record the numbers spoken so far and on what turns they have been spoken
     let mut nums_spoken: HashMap<u64, Vec<u64>> = HashMap::new();
     let mut last_num_spoken = 0;
@@ -41,162 +61,9 @@ fn play_memory_game(num_turns: usize, starting_numbers: &Vec<u64>) -> u64 {
             }
         };
         // Record the number as having been spoken
-        if !nums_spoken.contains_key(&number_spoken) {
-            nums_spoken.insert(number_spoken, vec![current_turn as u64]);
-        } else {
-            nums_spoken.get_mut(&number_spoken).unwrap().push(current_turn as u64);
-        }
+        nums_spoken.entry(number_spoken).or_default().push(current_turn as u64);
         // End of current turn
         last_num_spoken = number_spoken;
     }
-    return last_num_spoken;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d15_p1_proper() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/day15.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(371, result);
-    }
-
-    #[test]
-    fn test_d15_p2_proper() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/day15.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(352, result);
-    }
-
-    #[test]
-    fn test_d15_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(436, result);
-    }
-
-    #[test]
-    fn test_d15_p1_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_002.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(1, result);
-    }
-
-    #[test]
-    fn test_d15_p1_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_003.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(10, result);
-    }
-
-    #[test]
-    fn test_d15_p1_004() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_004.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(27, result);
-    }
-
-    #[test]
-    fn test_d15_p1_005() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_005.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(78, result);
-    }
-
-    #[test]
-    fn test_d15_p1_006() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_006.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(438, result);
-    }
-
-    #[test]
-    fn test_d15_p1_007() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_007.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(1836, result);
-    }
-
-    #[test]
-    fn test_d15_p2_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_001.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(175594, result);
-    }
-
-    #[test]
-    fn test_d15_p2_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_002.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(2578, result);
-    }
-
-    #[test]
-    fn test_d15_p2_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_003.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(3544142, result);
-    }
-
-    #[test]
-    fn test_d15_p2_004() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_004.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(261214, result);
-    }
-
-    #[test]
-    fn test_d15_p2_005() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_005.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(6895259, result);
-    }
-
-    #[test]
-    fn test_d15_p2_006() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_006.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(18, result);
-    }
-
-    #[test]
-    fn test_d15_p2_007() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day15_test_007.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(362, result);
-    }
+    last_num_spoken
 }
@@ -2,6 +2,8 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::RangeInclusive;
 
+use super::error::ParseError;
+
 use regex::Regex;
 
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
@@ -30,19 +32,24 @@ impl TicketRule {
                 return true;
             }
         }
-        return false;
+        false
     }
 }
 
 /// Stores the details captured in the train ticket dossier gathered in AOC 2020 Day 16.
-struct TrainTicketDossier {
+pub struct TrainTicketDossier {
     field_rules: HashMap<String, TicketRule>,
     own_ticket: Vec<u64>,
     nearby_tickets: Vec<Vec<u64>>,
 }
 
+/// Solution for AOC 2020 Day 16 (Ticket Translation).
+pub struct Day16;
+
+impl_solution!(Day16, day = 16, input = TrainTicketDossier);
+
 #[aoc_generator(day16)]
-fn generate_input(input: &str) -> TrainTicketDossier {
+fn generate_input(input: &str) -> Result<TrainTicketDossier, ParseError> {
     let field_rule_regex = Regex::new(r"^(.*): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
     let mut input_parse_state = InputParseState::FieldRules;
     // Set up variables to store data for train ticket dossier
@@ -50,9 +57,9 @@ fn generate_input(input: &str) -> TrainTicketDossier {
     let mut own_ticket: Vec<u64> = vec![];
     let mut nearby_tickets: Vec<Vec<u64>> = vec![];
     // Process each line
-    for line in input.lines() {
+    for (i, raw_line) in input.lines().enumerate() {
         // Check for empty lines - transitioning input parsing state as required
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             if input_parse_state == InputParseState::FieldRules {
                 input_parse_state = InputParseState::OwnTicket;
@@ -67,54 +74,68 @@ fn generate_input(input: &str) -> TrainTicketDossier {
                 if field_rule_regex.is_match(line) {
                     let captures = field_rule_regex.captures(line).unwrap();
                     let field_name = captures[1].to_string();
-                    let range_1_lower = captures[2].parse::<u64>().unwrap();
-                    let range_1_upper = captures[3].parse::<u64>().unwrap();
-                    let range_2_lower = captures[4].parse::<u64>().unwrap();
-                    let range_2_upper = captures[5].parse::<u64>().unwrap();
+                    let mut bounds: Vec<u64> = vec![];
+                    for j in 2..=5 {
+                        let bound = captures.get(j).unwrap().as_str();
+                        match bound.parse::<u64>() {
+                            Ok(bound) => bounds.push(bound),
+                            Err(_) => return Err(ParseError::at_text(
+                                16, i, raw_line, bound, "field rule bound out of range"
+                            )),
+                        }
+                    }
                     let mut ticket_rule = TicketRule::new();
-                    ticket_rule.add_new_range_inclusive(range_1_lower, range_1_upper);
-                    ticket_rule.add_new_range_inclusive(range_2_lower, range_2_upper);
+                    ticket_rule.add_new_range_inclusive(bounds[0], bounds[1]);
+                    ticket_rule.add_new_range_inclusive(bounds[2], bounds[3]);
                     field_rules.insert(field_name, ticket_rule);
                 } else {
-                    panic!("Day 16 - malformed input file. Failed during field rule parsing.");
+                    return Err(ParseError::at_line(16, i, raw_line, "malformed field rule"));
                 }
             }
             InputParseState::OwnTicket => {
                 if line == "your ticket:" {
                     continue;
                 }
-                own_ticket = line
-                    .split(",")
-                    .map(|x| x.parse::<u64>().unwrap())
-                    .collect::<Vec<u64>>();
+                own_ticket = parse_ticket(i, raw_line)?;
             }
             InputParseState::NearbyTickets => {
                 if line == "nearby tickets:" {
                     continue;
                 }
-                let ticket = line
-                    .split(",")
-                    .map(|x| x.parse::<u64>().unwrap())
-                    .collect::<Vec<u64>>();
+                let ticket = parse_ticket(i, raw_line)?;
                 nearby_tickets.push(ticket);
             }
         }
     }
-    return TrainTicketDossier {
-        field_rules: field_rules,
-        own_ticket: own_ticket,
-        nearby_tickets: nearby_tickets,
-    };
+    Ok(TrainTicketDossier {
+        field_rules,
+        own_ticket,
+        nearby_tickets,
+    })
+}
+
+/// Parses the comma-separated field values of a ticket from the given line of input.
+fn parse_ticket(line_index: usize, line: &str) -> Result<Vec<u64>, ParseError> {
+    let mut ticket: Vec<u64> = vec![];
+    for value in line.trim().split(",") {
+        match value.parse::<u64>() {
+            Ok(value) => ticket.push(value),
+            Err(_) => return Err(ParseError::at_text(
+                16, line_index, line, value, "bad ticket value"
+            )),
+        }
+    }
+    Ok(ticket)
 }
 
 /// Checks the validity of the given value against the given record of ticket rules.
 fn check_value_validity(value: u64, field_rules: &HashMap<String, TicketRule>) -> bool {
-    for (_field_name, rule) in field_rules {
+    for rule in field_rules.values() {
         if rule.check_value_validity(value) {
             return true;
         }
     }
-    return false;
+    false
 }
 
 #[aoc(day16, part1)]
@@ -128,7 +149,7 @@ fn solve_part_1(train_ticket_dossier: &TrainTicketDossier) -> u64 {
             }
         }
     }
-    return error_rate;
+    error_rate
 }
 
 #[aoc(day16, part2)]
@@ -190,33 +211,5 @@ fn solve_part_2(train_ticket_dossier: &TrainTicketDossier) -> u64 {
         .filter(|(f, _i)| f.starts_with("departure"))
         .map(|(_f, i)| train_ticket_dossier.own_ticket[*i])
         .product::<u64>();
-    return product;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d16_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day16.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(19060, result);
-    }
-
-    #[test]
-    fn test_d16_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day16.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(953713095011, result);
-    }
-
-    #[test]
-    fn test_d16_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day16_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(71, result);
-    }
+    product
 }
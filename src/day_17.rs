@@ -1,58 +1,147 @@
-use super::utils::machines::ConwayCube3D;
-use super::utils::machines::ConwayCube4D;
+use std::io;
+
+use super::error::ParseError;
+use super::utils::machines::run_until_repeat;
+use super::utils::machines::ConwayCube;
+use super::utils::render::FrameFormat;
+use super::utils::render::FrameWriter;
+
+/// Solution for AOC 2020 Day 17 (Conway Cubes).
+pub struct Day17;
+
+impl_solution!(Day17, day = 17, input = String);
 
 #[aoc_generator(day17)]
-fn generate_input(input: &str) -> String {
-    return input.to_string();
+fn generate_input(input: &str) -> Result<String, ParseError> {
+    // Each cube in the initial slice must be either active (#) or inactive (.)
+    for (i, raw_line) in input.lines().enumerate() {
+        if let Some((j, c)) = raw_line.trim().char_indices().find(|(_, c)| *c != '#' && *c != '.') {
+            let text = &raw_line.trim()[j..j + c.len_utf8()];
+            return Err(ParseError::at_text(17, i, raw_line, text, "bad cube state character"));
+        }
+    }
+    Ok(input.to_string())
 }
 
 #[aoc(day17, part1)]
-fn solve_part_1(input: &String) -> u64 {
-    let mut conway_cube_3d = ConwayCube3D::new(input);
-    for _ in 0..6 {
-        conway_cube_3d.conduct_step();
-    }
-    return conway_cube_3d.count_active_cubes();
+fn solve_part_1(input: &str) -> u64 {
+    let mut conway_cube_3d = ConwayCube::<3>::new(input).with_mirror_symmetry();
+    run_until_repeat(&mut conway_cube_3d, 6).get_population(6).unwrap()
 }
 
 #[aoc(day17, part2)]
-fn solve_part_2(input: &String) -> u64 {
-    let mut conway_cube_4d = ConwayCube4D::new(input);
-    for _ in 0..6 {
-        conway_cube_4d.conduct_step();
+fn solve_part_2(input: &str) -> u64 {
+    let mut conway_cube_4d = ConwayCube::<4>::new(input).with_mirror_symmetry();
+    run_until_repeat(&mut conway_cube_4d, 6).get_population(6).unwrap()
+}
+
+/// Writes a frame showing the active region of the Conway Cube (3D for part 1, 4D for part 2) for
+/// the initial state and after each of the six steps. Frames can only be written as ASCII text.
+pub fn write_frames(input: &str, part: u32, writer: &mut FrameWriter) -> io::Result<()> {
+    if writer.get_format() != FrameFormat::Ascii {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput, "Day 17 - frames can only be written as ASCII text"
+        ));
     }
-    return conway_cube_4d.count_active_cubes();
+    match part {
+        1 => {
+            let mut conway_cube_3d = ConwayCube::<3>::new(input).with_mirror_symmetry();
+            writer.write_frame(format!("{}\n", conway_cube_3d).as_bytes())?;
+            for _ in 0..6 {
+                conway_cube_3d.conduct_step();
+                writer.write_frame(format!("{}\n", conway_cube_3d).as_bytes())?;
+            }
+        },
+        2 => {
+            let mut conway_cube_4d = ConwayCube::<4>::new(input).with_mirror_symmetry();
+            writer.write_frame(format!("{}\n", conway_cube_4d).as_bytes())?;
+            for _ in 0..6 {
+                conway_cube_4d.conduct_step();
+                writer.write_frame(format!("{}\n", conway_cube_4d).as_bytes())?;
+            }
+        },
+        _ => panic!("Day 17 - bad part number: {}", part),
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::utils::machines::CellularAutomaton;
+    use super::super::utils::machines::LifeRule;
+    use super::super::utils::map::Neighbourhood;
+
+    /// Checks that exploiting mirror symmetry gives the same Conway Cube as the full simulation,
+    /// in the number of active cubes and the formatted active region after each step.
+    fn check_mirror_symmetry<const D: usize>(input: &str, steps: u64) {
+        let mut full = ConwayCube::<D>::new(input);
+        let mut mirrored = ConwayCube::<D>::new(input).with_mirror_symmetry();
+        for _ in 0..steps {
+            full.conduct_step();
+            mirrored.conduct_step();
+            assert_eq!(full.count_active_cubes(), mirrored.count_active_cubes());
+            assert_eq!(full.to_string(), mirrored.to_string());
+        }
+    }
+
+    #[test]
+    fn test_d17_mirror_symmetry_001() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day17_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        check_mirror_symmetry::<3>(&input, 6);
+        check_mirror_symmetry::<4>(&input, 6);
+        check_mirror_symmetry::<5>(&input, 3);
+    }
 
     #[test]
-    fn test_d17_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day17.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(388, result);
+    fn test_d17_mirror_symmetry_von_neumann() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day17_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        let rule = "B1/S1".parse::<LifeRule>().unwrap();
+        let mut full = ConwayCube::<4>::new(&input)
+            .with_rule(rule.clone())
+            .with_neighbourhood(Neighbourhood::von_neumann());
+        let mut mirrored = ConwayCube::<4>::new(&input)
+            .with_rule(rule)
+            .with_neighbourhood(Neighbourhood::von_neumann())
+            .with_mirror_symmetry();
+        for _ in 0..6 {
+            full.conduct_step();
+            mirrored.conduct_step();
+            assert_eq!(full.count_active_cubes(), mirrored.count_active_cubes());
+        }
     }
 
     #[test]
-    fn test_d17_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day17.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(2280, result);
+    #[should_panic(expected = "(B0) is not supported: B03/S23")]
+    fn test_d17_rule_b0_rejected() {
+        let rule = "B03/S23".parse::<LifeRule>().unwrap();
+        ConwayCube::<2>::new(".#.\n.#.\n.#.").with_rule(rule);
     }
 
     #[test]
-    fn test_d17_p1_001() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/test/day17_test_001.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(112, result);
+    fn test_d17_history_blinker() {
+        let mut blinker = ConwayCube::<2>::new(".#.\n.#.\n.#.");
+        let history = run_until_repeat(&mut blinker, 100);
+        assert!(history.is_cycle());
+        assert_eq!(Some(0), history.get_cycle_start());
+        assert_eq!(Some(2), history.get_period());
+        assert_eq!(2, blinker.get_steps());
+        assert_eq!(Some(3), history.get_population(99));
     }
 
     #[test]
-    fn test_d17_p2_001() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/test/day17_test_001.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(848, result);
+    fn test_d17_history_block() {
+        let mut block = ConwayCube::<2>::new("##\n##").with_mirror_symmetry();
+        let history = run_until_repeat(&mut block, 100);
+        assert!(history.is_fixed_point());
+        assert_eq!(Some(0), history.get_cycle_start());
+        assert_eq!(&vec![4, 4], history.get_populations());
+        assert_eq!(4, block.get_population());
     }
 }
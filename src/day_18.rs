@@ -1,9 +1,11 @@
 use std::collections::VecDeque;
 
+use super::error::ParseError;
+
 use regex::Regex;
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq)]
-enum Token {
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum Token {
     Operand { value: u64 },
     OperatorPlus,
     OperatorMult,
@@ -16,9 +18,8 @@ impl Token {
         let num_regex = Regex::new(r"^\d+$").unwrap();
         let input = input.trim();
         if num_regex.is_match(input) {
-            return Some(Token::Operand {
-                value: input.parse::<u64>().unwrap(),
-            });
+            // Operands too large to be represented cannot be parsed
+            return input.parse::<u64>().ok().map(|value| Token::Operand { value });
         } else if input == "+" {
             return Some(Token::OperatorPlus);
         } else if input == "*" {
@@ -28,52 +29,109 @@ impl Token {
         } else if input == ")" {
             return Some(Token::ParenClose);
         }
-        panic!("Token - failed to parse input string!");
+        None
     }
 }
 
+/// Solution for AOC 2020 Day 18 (Operation Order).
+pub struct Day18;
+
+impl_solution!(Day18, day = 18, input = Vec<Vec<Token>>);
+
 #[aoc_generator(day18)]
-fn generate_input(input: &str) -> Vec<Vec<Token>> {
+fn generate_input(input: &str) -> Result<Vec<Vec<Token>>, ParseError> {
     let mut expressions: Vec<Vec<Token>> = vec![];
-    for line in input.lines() {
+    for (i, raw_line) in input.lines().enumerate() {
         let mut exp: Vec<Token> = vec![];
         // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
-        // Ensure open and close parentheses are surrounded by whitespace
-        let line = line.replace("(", " ( ").replace(")", " ) ");
-        for raw_token in line.split_whitespace() {
-            exp.push(Token::from_string(&raw_token).unwrap());
+        // Operands and operators must alternate, with parentheses balanced
+        let mut expect_operand = true;
+        let mut open_parens: Vec<&str> = vec![];
+        for raw_token in split_tokens(line) {
+            let token = match Token::from_string(raw_token) {
+                Some(token) => token,
+                None => return Err(ParseError::at_text(18, i, raw_line, raw_token, "bad token")),
+            };
+            let message = match token {
+                Token::Operand { value: _ } | Token::ParenOpen if !expect_operand => {
+                    Some("expected operator or ')'")
+                },
+                Token::OperatorPlus | Token::OperatorMult | Token::ParenClose if expect_operand => {
+                    Some("expected operand or '('")
+                },
+                Token::ParenClose if open_parens.pop().is_none() => Some("unmatched ')'"),
+                _ => None,
+            };
+            if let Some(message) = message {
+                return Err(ParseError::at_text(18, i, raw_line, raw_token, message));
+            }
+            match token {
+                Token::ParenOpen => open_parens.push(raw_token),
+                Token::Operand { value: _ } | Token::ParenClose => expect_operand = false,
+                Token::OperatorPlus | Token::OperatorMult => expect_operand = true,
+            }
+            exp.push(token);
+        }
+        if expect_operand {
+            let end = &line[line.len()..];
+            return Err(ParseError::at_text(18, i, raw_line, end, "expected operand or '('"));
+        }
+        if let Some(raw_token) = open_parens.pop() {
+            return Err(ParseError::at_text(18, i, raw_line, raw_token, "unclosed '('"));
         }
         expressions.push(exp);
     }
-    return expressions;
+    Ok(expressions)
+}
+
+/// Splits the line into the text of its tokens, with each parenthesis being a separate token even
+/// when not surrounded by whitespace. Each token is a slice of the line, so it can be located.
+fn split_tokens(line: &str) -> Vec<&str> {
+    let mut raw_tokens: Vec<&str> = vec![];
+    for word in line.split_whitespace() {
+        let mut start = 0;
+        for (j, c) in word.char_indices() {
+            if c == '(' || c == ')' {
+                if start < j {
+                    raw_tokens.push(&word[start..j]);
+                }
+                raw_tokens.push(&word[j..j + 1]);
+                start = j + 1;
+            }
+        }
+        if start < word.len() {
+            raw_tokens.push(&word[start..]);
+        }
+    }
+    raw_tokens
 }
 
 #[aoc(day18, part1)]
-fn solve_part_1(expressions: &Vec<Vec<Token>>) -> u64 {
+fn solve_part_1(expressions: &[Vec<Token>]) -> u64 {
     let mut sum_result = 0;
     for exp in expressions {
         let mut index = 0;
-        sum_result += evaluate_expression_recursive(exp, &mut index, 0);
+        sum_result += evaluate_expression_recursive(exp, &mut index);
     }
-    return sum_result;
+    sum_result
 }
 
 #[aoc(day18, part2)]
-fn solve_part_2(expressions: &Vec<Vec<Token>>) -> u64 {
+fn solve_part_2(expressions: &[Vec<Token>]) -> u64 {
     let mut sum_result = 0;
     for exp in expressions {
         let exp_rpn = convert_exp_infix_to_rpn(exp);
         sum_result += evaluate_expression_rpn(&exp_rpn);
     }
-    return sum_result;
+    sum_result
 }
 
 /// Converts an expression in infix notation to Reverse Polish Notation (RPN) (postfix).
-fn convert_exp_infix_to_rpn(exp: &Vec<Token>) -> Vec<Token> {
+fn convert_exp_infix_to_rpn(exp: &[Token]) -> Vec<Token> {
     let mut output: Vec<Token> = vec![];
     let mut op_stack: VecDeque<Token> = VecDeque::new();
     for token in exp.iter() {
@@ -101,11 +159,11 @@ fn convert_exp_infix_to_rpn(exp: &Vec<Token>) -> Vec<Token> {
     while !op_stack.is_empty() {
         output.push(op_stack.pop_front().unwrap());
     }
-    return output;
+    output
 }
 
 /// Evaluates the given expression in Reverse Polish Notation (postfix)
-fn evaluate_expression_rpn(exp_rpn: &Vec<Token>) -> u64 {
+fn evaluate_expression_rpn(exp_rpn: &[Token]) -> u64 {
     let mut result_stack: VecDeque<u64> = VecDeque::new();
     for token in exp_rpn.iter() {
         let token = *token;
@@ -126,12 +184,12 @@ fn evaluate_expression_rpn(exp_rpn: &Vec<Token>) -> u64 {
             _ => (),
         }
     }
-    return result_stack.pop_front().unwrap();
+    result_stack.pop_front().unwrap()
 }
 
 /// Evaluates the given expression in infix notation, assuming all operators have the same
 /// precedence.
-fn evaluate_expression_recursive(exp_infix: &Vec<Token>, index: &mut usize, depth: usize) -> u64 {
+fn evaluate_expression_recursive(exp_infix: &[Token], index: &mut usize) -> u64 {
     let mut result = 0;
     let mut last_operator = Token::OperatorPlus;
     while *index < exp_infix.len() {
@@ -148,7 +206,7 @@ fn evaluate_expression_recursive(exp_infix: &Vec<Token>, index: &mut usize, dept
             Token::OperatorPlus => last_operator = token,
             Token::ParenOpen => {
                 *index += 1;
-                let sub_result = evaluate_expression_recursive(exp_infix, index, depth + 1);
+                let sub_result = evaluate_expression_recursive(exp_infix, index);
                 if last_operator == Token::OperatorPlus {
                     result += sub_result;
                 } else if last_operator == Token::OperatorMult {
@@ -161,7 +219,7 @@ fn evaluate_expression_recursive(exp_infix: &Vec<Token>, index: &mut usize, dept
         }
         *index += 1;
     }
-    return result;
+    result
 }
 
 #[cfg(test)]
@@ -169,106 +227,53 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_d18_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day18.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(45283905029161, result);
-    }
-
-    #[test]
-    fn test_d18_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day18.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(216975281211165, result);
-    }
-
-    #[test]
-    fn test_d18_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(71, result);
-    }
-
-    #[test]
-    fn test_d18_p1_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_002.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(26, result);
-    }
-
-    #[test]
-    fn test_d18_p1_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_003.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(437, result);
-    }
-
-    #[test]
-    fn test_d18_p1_004() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_004.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(12240, result);
-    }
-
-    #[test]
-    fn test_d18_p1_005() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_005.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(13632, result);
+    fn test_d18_parse_error_006() {
+        let error = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day18_test_006.txt").unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(18, error.get_day());
+        assert_eq!(2, error.get_line());
+        assert_eq!(10, error.get_column());
+        assert_eq!("x", error.get_text());
     }
-
-    #[test]
-    fn test_d18_p2_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_001.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(231, result);
-    }
-
     #[test]
-    fn test_d18_p2_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_002.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(46, result);
+    fn test_d18_parse_error_unclosed_paren() {
+        let error = generate_input("1 + (2\n").unwrap_err();
+        assert_eq!(1, error.get_line());
+        assert_eq!(5, error.get_column());
+        assert_eq!("(", error.get_text());
     }
 
     #[test]
-    fn test_d18_p2_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_003.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(1445, result);
+    fn test_d18_parse_error_trailing_operator() {
+        let error = generate_input("1 + 2\n3 * (1 + 2) + \n").unwrap_err();
+        assert_eq!(2, error.get_line());
+        assert_eq!(14, error.get_column());
+        assert_eq!("", error.get_text());
     }
 
     #[test]
-    fn test_d18_p2_004() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_004.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(669060, result);
+    fn test_d18_parse_error_unmatched_paren() {
+        let error = generate_input(") 1\n").unwrap_err();
+        assert_eq!(1, error.get_line());
+        assert_eq!(1, error.get_column());
+        assert_eq!(")", error.get_text());
+        let error = generate_input("(1 + 2)) * 3\n").unwrap_err();
+        assert_eq!(8, error.get_column());
+        assert_eq!(")", error.get_text());
     }
 
     #[test]
-    fn test_d18_p2_005() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day18_test_005.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(23340, result);
+    fn test_d18_parse_error_operands_alternate() {
+        let error = generate_input("1 + 2 3\n").unwrap_err();
+        assert_eq!(7, error.get_column());
+        assert_eq!("3", error.get_text());
+        let error = generate_input("2 * (3 + * 4)\n").unwrap_err();
+        assert_eq!(10, error.get_column());
+        assert_eq!("*", error.get_text());
+        let error = generate_input("2 (3)\n").unwrap_err();
+        assert_eq!(3, error.get_column());
+        assert_eq!("(", error.get_text());
     }
 }
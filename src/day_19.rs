@@ -1,30 +1,102 @@
 use std::collections::HashMap;
 
+use super::error::ParseError;
+
 use regex::Captures;
 use regex::Regex;
 
+/// Raw rule bodies keyed by rule ID, followed by the received messages.
+type RulesAndMessages = (HashMap<u64, String>, Vec<String>);
+
+/// Solution for AOC 2020 Day 19 (Monster Messages).
+pub struct Day19;
+
+impl_solution!(Day19, day = 19, input = RulesAndMessages);
+
 #[aoc_generator(day19)]
-fn generate_input(input: &str) -> (HashMap<u64, String>, Vec<String>) {
-    let rule_regex = Regex::new(r"(\d+): (.*)").unwrap();
-    // Split input into two segments
-    let segments = input.trim().split("\n\n").collect::<Vec<&str>>();
-    // Extract rules
-    let rules_raw = segments[0]
-        .lines()
-        .map(|x| x.trim())
-        .map(|x| rule_regex.captures(x).unwrap())
-        .map(|x| (x[1].parse::<u64>().unwrap(), x[2].to_string()))
-        .collect::<HashMap<u64, String>>();
-    // Extract messages
-    let messages = segments[1]
-        .lines()
-        .map(|x| x.trim().to_string())
-        .collect::<Vec<String>>();
-    return (rules_raw, messages);
+fn generate_input(input: &str) -> Result<RulesAndMessages, ParseError> {
+    let rule_regex = Regex::new(r"^(\d+): (.*)$").unwrap();
+    let mut rules_raw: HashMap<u64, String> = HashMap::new();
+    let mut messages: Vec<String> = vec![];
+    // Rule IDs referenced by other rules, kept with their location until all rules are read
+    let mut references: Vec<(usize, &str, &str)> = vec![];
+    // Rules are separated from the messages by an empty line
+    let mut reading_rules = true;
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            if !rules_raw.is_empty() {
+                reading_rules = false;
+            }
+            continue;
+        }
+        if reading_rules {
+            // Extract rule
+            let captures = match rule_regex.captures(line) {
+                Some(captures) => captures,
+                None => return Err(ParseError::at_line(19, i, raw_line, "malformed rule")),
+            };
+            let rule_id = match captures[1].parse::<u64>() {
+                Ok(rule_id) => rule_id,
+                Err(_) => {
+                    let text = captures.get(1).unwrap().as_str();
+                    return Err(ParseError::at_text(19, i, raw_line, text, "rule ID out of range"));
+                }
+            };
+            let body = captures.get(2).unwrap().as_str();
+            validate_rule_body(i, raw_line, body, &mut references)?;
+            rules_raw.insert(rule_id, body.to_string());
+        } else {
+            // Extract message
+            messages.push(line.to_string());
+        }
+    }
+    for (i, raw_line, text) in references {
+        if !rules_raw.contains_key(&text.parse::<u64>().unwrap()) {
+            return Err(ParseError::at_text(19, i, raw_line, text, "missing rule"));
+        }
+    }
+    if !rules_raw.contains_key(&0) {
+        return Err(ParseError::at_end(19, input, "missing rule 0"));
+    }
+    Ok((rules_raw, messages))
+}
+
+/// Checks that the rule body is either a single quoted character, or lists of rule IDs separated
+/// by '|'. The rule IDs in the body are added to the references, to be checked once all rules
+/// have been read.
+fn validate_rule_body<'a>(
+    line_index: usize,
+    raw_line: &'a str,
+    body: &'a str,
+    references: &mut Vec<(usize, &'a str, &'a str)>,
+) -> Result<(), ParseError> {
+    if body.starts_with('"') {
+        let chars = body.chars().collect::<Vec<char>>();
+        if chars.len() != 3 || chars[2] != '"' || chars[1] == '"' {
+            let message = "expected a single quoted character";
+            return Err(ParseError::at_text(19, line_index, raw_line, body, message));
+        }
+        return Ok(());
+    }
+    for alternative in body.split('|') {
+        if alternative.trim().is_empty() {
+            let message = "expected rule IDs";
+            return Err(ParseError::at_text(19, line_index, raw_line, alternative, message));
+        }
+        for text in alternative.split_whitespace() {
+            if text.parse::<u64>().is_err() {
+                let message = "bad rule ID";
+                return Err(ParseError::at_text(19, line_index, raw_line, text, message));
+            }
+            references.push((line_index, raw_line, text));
+        }
+    }
+    Ok(())
 }
 
 #[aoc(day19, part1)]
-fn solve_part_1(input: &(HashMap<u64, String>, Vec<String>)) -> u64 {
+fn solve_part_1(input: &RulesAndMessages) -> u64 {
     let rules_raw = input.0.clone();
     let messages = input.1.clone();
     // Generate regex for rule 0
@@ -39,11 +111,11 @@ fn solve_part_1(input: &(HashMap<u64, String>, Vec<String>)) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
+    valid_count
 }
 
 #[aoc(day19, part2)]
-fn solve_part_2(input: &(HashMap<u64, String>, Vec<String>)) -> u64 {
+fn solve_part_2(input: &RulesAndMessages) -> u64 {
     let mut rules_raw = input.0.clone();
     let messages = input.1.clone();
     // Make replacements of rules 8 and 11 - amended to allow matching of looped rule
@@ -64,7 +136,7 @@ fn solve_part_2(input: &(HashMap<u64, String>, Vec<String>)) -> u64 {
             valid_count += 1;
         }
     }
-    return valid_count;
+    valid_count
 }
 
 fn generate_regex_str_from_rules(
@@ -108,7 +180,7 @@ fn generate_regex_str_from_rules(
         };
     }
     regex_str = regex_str.replace(" ", "");
-    return Some(regex_str);
+    Some(regex_str)
 }
 
 #[cfg(test)]
@@ -116,34 +188,27 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_d19_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day19.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(184, result);
-    }
-
-    #[test]
-    fn test_d19_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day19.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(389, result);
-    }
-
-    #[test]
-    fn test_d19_p1_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day19_test_002.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(2, result);
+    fn test_d19_parse_error_missing_rule() {
+        let error = generate_input("0: 2 1\n2: \"a\"\n\naa\n").unwrap_err();
+        assert_eq!(19, error.get_day());
+        assert_eq!(1, error.get_line());
+        assert_eq!(6, error.get_column());
+        assert_eq!("1", error.get_text());
+        let error = generate_input("1: \"a\"\n\naa\n").unwrap_err();
+        assert_eq!("missing rule 0", error.get_message());
     }
 
     #[test]
-    fn test_d19_p2_003() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day19_test_003.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(12, result);
+    fn test_d19_parse_error_truncated_rule() {
+        let error = generate_input("0: 4 4\n4: \"a\n\naa\n").unwrap_err();
+        assert_eq!(2, error.get_line());
+        assert_eq!(4, error.get_column());
+        assert_eq!("\"a", error.get_text());
+        let error = generate_input("0: 4 4 |\n4: \"a\"\n\naa\n").unwrap_err();
+        assert_eq!(1, error.get_line());
+        assert_eq!("expected rule IDs", error.get_message());
+        let error = generate_input("0: 4 x\n4: \"a\"\n\naa\n").unwrap_err();
+        assert_eq!(6, error.get_column());
+        assert_eq!("x", error.get_text());
     }
 }
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use super::error::ParseError;
+use super::utils::map::ImageTile;
+use super::utils::map::TileJigsaw;
+
+use regex::Regex;
+
+/// Pattern of the sea monster to be found within the assembled image.
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Solution for AOC 2020 Day 20 (Jurassic Jigsaw).
+pub struct Day20;
+
+impl_solution!(Day20, day = 20, input = Vec<ImageTile>);
+
+#[aoc_generator(day20)]
+fn generate_input(input: &str) -> Result<Vec<ImageTile>, ParseError> {
+    let tile_id_regex = Regex::new(r"^Tile (\d+):$").unwrap();
+    let mut tiles: Vec<ImageTile> = vec![];
+    // Tile currently being read - the line index and line of its header, its ID and pixels
+    let mut current_tile: Option<(usize, &str, u64, Vec<Vec<char>>)> = None;
+    // Each tile is separated from the next by an empty line
+    for (i, raw_line) in input.lines().chain(std::iter::once("")).enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            if let Some((header_index, header, id, pixels)) = current_tile.take() {
+                match ImageTile::new(id, pixels) {
+                    Some(tile) => tiles.push(tile),
+                    None => return Err(ParseError::at_line(
+                        20, header_index, header, "tile is not square"
+                    )),
+                }
+            }
+            continue;
+        }
+        match current_tile.as_mut() {
+            Some((_, _, _, pixels)) => pixels.push(line.chars().collect::<Vec<char>>()),
+            None => {
+                let captures = match tile_id_regex.captures(line) {
+                    Some(captures) => captures,
+                    None => return Err(ParseError::at_line(
+                        20, i, raw_line, "malformed tile header"
+                    )),
+                };
+                let id = match captures[1].parse::<u64>() {
+                    Ok(id) => id,
+                    Err(_) => {
+                        let text = captures.get(1).unwrap().as_str();
+                        return Err(ParseError::at_text(
+                            20, i, raw_line, text, "tile ID out of range"
+                        ));
+                    }
+                };
+                current_tile = Some((i, raw_line, id, vec![]));
+            }
+        }
+    }
+    Ok(tiles)
+}
+
+#[aoc(day20, part1)]
+fn solve_part_1(tiles: &[ImageTile]) -> u64 {
+    let jigsaw = TileJigsaw::new(tiles);
+    let corner_ids = jigsaw.find_corner_tile_ids();
+    if corner_ids.len() != 4 {
+        panic!("Day 20 Part 1 - did not find exactly four corner tiles!");
+    }
+    corner_ids.iter().product()
+}
+
+#[aoc(day20, part2)]
+fn solve_part_2(tiles: &[ImageTile]) -> u64 {
+    let jigsaw = TileJigsaw::new(tiles);
+    let image = match jigsaw.assemble_image() {
+        Some(image) => image,
+        None => panic!("Day 20 Part 2 - could not assemble image from tiles!"),
+    };
+    // Check each orientation of the image until sea monsters are found
+    for orientation in image.get_all_orientations() {
+        let monster_pixels = find_sea_monster_pixels(&orientation);
+        if !monster_pixels.is_empty() {
+            return orientation.count_pixels('#') - monster_pixels.len() as u64;
+        }
+    }
+    panic!("Day 20 Part 2 - did not find any sea monsters!");
+}
+
+/// Finds the locations (row, col) of all pixels in the image that form part of a sea monster.
+fn find_sea_monster_pixels(image: &ImageTile) -> HashSet<(usize, usize)> {
+    // Determine the offsets of the pixels making up the sea monster
+    let mut monster_offsets: Vec<(usize, usize)> = vec![];
+    for (row, line) in SEA_MONSTER.iter().enumerate() {
+        for (col, c) in line.chars().enumerate() {
+            if c == '#' {
+                monster_offsets.push((row, col));
+            }
+        }
+    }
+    let monster_height = SEA_MONSTER.len();
+    let monster_width = SEA_MONSTER[0].len();
+    // Check each location in the image for the top-left corner of a sea monster
+    let pixels = image.get_pixels();
+    let size = image.get_size();
+    let mut monster_pixels: HashSet<(usize, usize)> = HashSet::new();
+    if size < monster_height || size < monster_width {
+        return monster_pixels;
+    }
+    for row in 0..=(size - monster_height) {
+        for col in 0..=(size - monster_width) {
+            let found = monster_offsets
+                .iter()
+                .all(|(d_row, d_col)| pixels[row + d_row][col + d_col] == '#');
+            if found {
+                for (d_row, d_col) in monster_offsets.iter() {
+                    monster_pixels.insert((row + d_row, col + d_col));
+                }
+            }
+        }
+    }
+    monster_pixels
+}
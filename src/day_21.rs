@@ -1,21 +1,31 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use super::error::ParseError;
+
 use regex::Regex;
 
 #[derive(Clone)]
-struct Food {
+pub struct Food {
     allergens: HashSet<String>,
     ingredients: HashSet<String>,
 }
 
+/// Solution for AOC 2020 Day 21 (Allergen Assessment).
+pub struct Day21;
+
+impl_solution!(Day21, day = 21, input = Vec<Food>);
+
 #[aoc_generator(day21)]
-fn generate_input(input: &str) -> Vec<Food> {
+fn generate_input(input: &str) -> Result<Vec<Food>, ParseError> {
     // Create empty vector to store food list, being listed allergens and ingredients
     let mut food_list: Vec<Food> = vec![];
     let food_regex = Regex::new(r"^(.*) \(contains (.*)\)$").unwrap();
-    for line in input.lines() {
-        let line = line.trim();
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
         if food_regex.is_match(line) {
             let captures = food_regex.captures(line).unwrap();
             let ingredients = captures[1]
@@ -27,16 +37,18 @@ fn generate_input(input: &str) -> Vec<Food> {
                 .map(|x| x.to_string())
                 .collect::<HashSet<String>>();
             food_list.push(Food {
-                allergens: allergens,
-                ingredients: ingredients,
+                allergens,
+                ingredients,
             });
+        } else {
+            return Err(ParseError::at_line(21, i, raw_line, "malformed food entry"));
         }
     }
-    return food_list;
+    Ok(food_list)
 }
 
 #[aoc(day21, part1)]
-fn solve_part_1(food_list: &Vec<Food>) -> u64 {
+fn solve_part_1(food_list: &[Food]) -> u64 {
     let inert_ingredients = determine_inert_ingredients(food_list);
     // Count number of times the allergen-free ingredients occur across all foods
     let mut count = 0;
@@ -51,11 +63,11 @@ fn solve_part_1(food_list: &Vec<Food>) -> u64 {
             }
         }
     }
-    return count;
+    count
 }
 
 #[aoc(day21, part2)]
-fn solve_part_2(food_list: &Vec<Food>) -> String {
+fn solve_part_2(food_list: &[Food]) -> String {
     // Get the list of inert ingredients and ingredients that may contain an allergen
     let inert_ingredients = determine_inert_ingredients(food_list);
     let mut allergen_ingredients = determine_potential_allergen_ingredients(food_list);
@@ -96,7 +108,7 @@ fn solve_part_2(food_list: &Vec<Food>) -> String {
     let mut dangerous_ingredients: Vec<String> = vec![];
     let mut allergens_sorted: Vec<String> = allergen_ingredients
         .keys()
-        .map(|x| x.clone())
+        .cloned()
         .collect::<Vec<String>>();
     allergens_sorted.sort();
     for allergen in allergens_sorted {
@@ -110,12 +122,12 @@ fn solve_part_2(food_list: &Vec<Food>) -> String {
                 .clone(),
         );
     }
-    return dangerous_ingredients.join(",");
+    dangerous_ingredients.join(",")
 }
 
 /// Determines all ingredients that are contained within foods with each observed listed allergen.
 fn determine_potential_allergen_ingredients(
-    food_list: &Vec<Food>,
+    food_list: &[Food],
 ) -> HashMap<String, HashSet<String>> {
     // For each allergen, find the union of all ingredient sets in which the allergen occurs
     let mut allergen_ingredients: HashMap<String, HashSet<String>> = HashMap::new();
@@ -132,18 +144,18 @@ fn determine_potential_allergen_ingredients(
                 let intersection = allergen_ingredients
                     .get(allergen)
                     .unwrap()
-                    .intersection(&ingredients)
-                    .map(|x| x.clone())
+                    .intersection(ingredients)
+                    .cloned()
                     .collect::<HashSet<String>>();
                 allergen_ingredients.insert(allergen.clone(), intersection);
             }
         }
     }
-    return allergen_ingredients;
+    allergen_ingredients
 }
 
 /// Determines the set of all ingredients observed for all foods in the given list.
-fn determine_all_ingredients(food_list: &Vec<Food>) -> HashSet<String> {
+fn determine_all_ingredients(food_list: &[Food]) -> HashSet<String> {
     // Determine set of all ingredients observed
     let mut all_ingredients: HashSet<String> = HashSet::new();
     for Food {
@@ -152,16 +164,16 @@ fn determine_all_ingredients(food_list: &Vec<Food>) -> HashSet<String> {
     } in food_list.iter()
     {
         all_ingredients = all_ingredients
-            .union(&ingredients)
-            .map(|x| x.clone())
+            .union(ingredients)
+            .cloned()
             .collect::<HashSet<String>>();
     }
-    return all_ingredients;
+    all_ingredients
 }
 
 /// Determines what ingredients observed in the given food list definitely do not contain one of the
 /// observed allergens.
-fn determine_inert_ingredients(food_list: &Vec<Food>) -> HashSet<String> {
+fn determine_inert_ingredients(food_list: &[Food]) -> HashSet<String> {
     // Determine set of all ingredients observed
     let all_ingredients = determine_all_ingredients(food_list);
     // For each allergen, find the union of all ingredient sets in which the allergen occurs
@@ -171,50 +183,13 @@ fn determine_inert_ingredients(food_list: &Vec<Food>) -> HashSet<String> {
     for (_allergen, ingredients) in allergen_ingredients {
         potential_allergen_ingredients = potential_allergen_ingredients
             .union(&ingredients)
-            .map(|x| x.clone())
+            .cloned()
             .collect::<HashSet<String>>();
     }
     // Determine allergen-free ingredients by removing potentially allergen-containing ingredients
     let inert_ingredients = all_ingredients
         .difference(&potential_allergen_ingredients)
-        .map(|x| x.clone())
+        .cloned()
         .collect::<HashSet<String>>();
-    return inert_ingredients;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_d21_p1_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day21.txt").unwrap());
-        let result = solve_part_1(&input);
-        assert_eq!(2659, result);
-    }
-
-    #[test]
-    fn test_d21_p2_proper() {
-        let input = generate_input(&std::fs::read_to_string("./input/2020/day21.txt").unwrap());
-        let result = solve_part_2(&input);
-        assert_eq!(String::from("rcqb,cltx,nrl,qjvvcvz,tsqpn,xhnk,tfqsb,zqzmzl"), result);
-    }
-
-    #[test]
-    fn test_d21_p1_001() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day21_test_001.txt").unwrap(),
-        );
-        let result = solve_part_1(&input);
-        assert_eq!(5, result);
-    }
-
-    #[test]
-    fn test_d21_p1_002() {
-        let input = generate_input(
-            &std::fs::read_to_string("./input/2020/test/day21_test_001.txt").unwrap(),
-        );
-        let result = solve_part_2(&input);
-        assert_eq!(String::from("mxmxvkd,sqjhc,fvjkl"), result);
-    }
+    inert_ingredients
 }
@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use super::error::ParseError;
+
+/// Represents one of the two players in a game of Combat.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum CombatPlayer {
+    Player1,
+    Player2,
+}
+
+impl CombatPlayer {
+    /// Gets the player number (1 or 2) as used in the game log.
+    fn get_number(&self) -> u64 {
+        match self {
+            CombatPlayer::Player1 => 1,
+            CombatPlayer::Player2 => 2,
+        }
+    }
+}
+
+/// Represents a game of Combat (or Recursive Combat) played by the crab, as introduced in AOC 2020
+/// Day 22. A round-by-round log of the game can optionally be recorded.
+pub struct CrabCombat {
+    deck_1: VecDeque<u64>,
+    deck_2: VecDeque<u64>,
+    recursive: bool,
+    record_log: bool,
+    log: Vec<String>,
+    game_count: u64,
+    winner: Option<CombatPlayer>,
+}
+
+impl CrabCombat {
+    /// Creates a new game of Combat using the given starting decks for each player. Recursive
+    /// Combat is played if specified.
+    pub fn new(deck_1: &[u64], deck_2: &[u64], recursive: bool) -> Self {
+        Self {
+            deck_1: deck_1.iter().copied().collect::<VecDeque<u64>>(),
+            deck_2: deck_2.iter().copied().collect::<VecDeque<u64>>(),
+            recursive,
+            record_log: false,
+            log: vec![],
+            game_count: 0,
+            winner: None,
+        }
+    }
+
+    /// Enables recording of the round-by-round game log, which can be retrieved after the game has
+    /// been played.
+    pub fn enable_log(&mut self) {
+        self.record_log = true;
+    }
+
+    /// Gets the lines of the game log recorded so far. The log is empty if logging was not enabled
+    /// before the game was played.
+    pub fn get_log(&self) -> &Vec<String> {
+        &self.log
+    }
+
+    /// Plays the game to completion and returns the winning player. If the game has already been
+    /// played, the previous winner is returned.
+    pub fn play(&mut self) -> CombatPlayer {
+        if let Some(winner) = self.winner {
+            return winner;
+        }
+        let deck_1 = self.deck_1.clone();
+        let deck_2 = self.deck_2.clone();
+        let (winner, deck_1, deck_2) = self.play_game(deck_1, deck_2);
+        self.deck_1 = deck_1;
+        self.deck_2 = deck_2;
+        self.winner = Some(winner);
+        if self.record_log {
+            self.log.push(String::from(""));
+            self.log.push(String::from("== Post-game results =="));
+            self.log.push(format!("Player 1's deck: {}", CrabCombat::deck_to_string(&self.deck_1)));
+            self.log.push(format!("Player 2's deck: {}", CrabCombat::deck_to_string(&self.deck_2)));
+        }
+        winner
+    }
+
+    /// Plays the game to completion and calculates the score of the winning player's deck.
+    pub fn calculate_winning_score(&mut self) -> u64 {
+        let winning_deck = match self.play() {
+            CombatPlayer::Player1 => &self.deck_1,
+            CombatPlayer::Player2 => &self.deck_2,
+        };
+        winning_deck
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, card)| (i as u64 + 1) * card)
+            .sum()
+    }
+
+    /// Plays a single game using the given decks, recursing into sub-games as required. Returns the
+    /// winner of the game and the final state of each player's deck.
+    fn play_game(&mut self, mut deck_1: VecDeque<u64>, mut deck_2: VecDeque<u64>)
+            -> (CombatPlayer, VecDeque<u64>, VecDeque<u64>)
+    {
+        self.game_count += 1;
+        let game = self.game_count;
+        self.log_line(|| format!("=== Game {} ===", game));
+        let mut seen_states: HashSet<(VecDeque<u64>, VecDeque<u64>)> = HashSet::new();
+        let mut round = 0;
+        let winner = loop {
+            // Check if either player has run out of cards
+            if deck_2.is_empty() {
+                break CombatPlayer::Player1;
+            }
+            if deck_1.is_empty() {
+                break CombatPlayer::Player2;
+            }
+            round += 1;
+            self.log_line(|| String::from(""));
+            self.log_line(|| format!("-- Round {} (Game {}) --", round, game));
+            self.log_line(|| format!("Player 1's deck: {}", CrabCombat::deck_to_string(&deck_1)));
+            self.log_line(|| format!("Player 2's deck: {}", CrabCombat::deck_to_string(&deck_2)));
+            // Repeated deck configurations end the game in favour of player 1 (Recursive Combat)
+            if self.recursive && !seen_states.insert((deck_1.clone(), deck_2.clone())) {
+                self.log_line(|| String::from("Repeated deck configuration - player 1 wins!"));
+                break CombatPlayer::Player1;
+            }
+            let card_1 = deck_1.pop_front().unwrap();
+            let card_2 = deck_2.pop_front().unwrap();
+            self.log_line(|| format!("Player 1 plays: {}", card_1));
+            self.log_line(|| format!("Player 2 plays: {}", card_2));
+            // Determine the winner of the round, recursing into a sub-game if required
+            let round_winner = if self.recursive
+                && deck_1.len() as u64 >= card_1
+                && deck_2.len() as u64 >= card_2
+            {
+                self.log_line(|| String::from("Playing a sub-game to determine the winner..."));
+                self.log_line(|| String::from(""));
+                let sub_deck_1 = deck_1.iter().take(card_1 as usize).copied().collect();
+                let sub_deck_2 = deck_2.iter().take(card_2 as usize).copied().collect();
+                let (sub_winner, _, _) = self.play_game(sub_deck_1, sub_deck_2);
+                self.log_line(|| String::from(""));
+                self.log_line(|| format!("...anyway, back to game {}.", game));
+                sub_winner
+            } else if card_1 > card_2 {
+                CombatPlayer::Player1
+            } else {
+                CombatPlayer::Player2
+            };
+            self.log_line(|| format!(
+                "Player {} wins round {} of game {}!", round_winner.get_number(), round, game
+            ));
+            // Winner of the round places both cards at the bottom of their deck, own card first
+            match round_winner {
+                CombatPlayer::Player1 => {
+                    deck_1.push_back(card_1);
+                    deck_1.push_back(card_2);
+                },
+                CombatPlayer::Player2 => {
+                    deck_2.push_back(card_2);
+                    deck_2.push_back(card_1);
+                },
+            }
+        };
+        self.log_line(|| format!("The winner of game {} is player {}!", game, winner.get_number()));
+        (winner, deck_1, deck_2)
+    }
+
+    /// Adds the line generated by the given function to the game log, if logging is enabled.
+    fn log_line<F: FnOnce() -> String>(&mut self, line: F) {
+        if self.record_log {
+            self.log.push(line());
+        }
+    }
+
+    /// Converts the given deck to a comma-separated list of cards, from top to bottom.
+    fn deck_to_string(deck: &VecDeque<u64>) -> String {
+        deck.iter().map(|card| card.to_string()).collect::<Vec<String>>().join(", ")
+    }
+}
+
+/// Solution for AOC 2020 Day 22 (Crab Combat).
+pub struct Day22;
+
+impl_solution!(Day22, day = 22, input = (Vec<u64>, Vec<u64>));
+
+#[aoc_generator(day22)]
+fn generate_input(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut decks: Vec<Vec<u64>> = vec![];
+    // Each player's deck is separated from the other by an empty line
+    let mut reading_deck = false;
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            reading_deck = false;
+            continue;
+        }
+        if !reading_deck {
+            if !line.starts_with("Player ") {
+                return Err(ParseError::at_line(22, i, raw_line, "malformed deck header"));
+            }
+            decks.push(vec![]);
+            reading_deck = true;
+            continue;
+        }
+        match line.parse::<u64>() {
+            Ok(card) => decks.last_mut().unwrap().push(card),
+            Err(_) => return Err(ParseError::at_line(22, i, raw_line, "card is not a number")),
+        }
+    }
+    if decks.len() != 2 {
+        return Err(ParseError::at_end(22, input, "input must contain exactly two decks"));
+    }
+    Ok((decks[0].clone(), decks[1].clone()))
+}
+
+#[aoc(day22, part1)]
+fn solve_part_1(decks: &(Vec<u64>, Vec<u64>)) -> u64 {
+    let mut game = CrabCombat::new(&decks.0, &decks.1, false);
+    game.calculate_winning_score()
+}
+
+#[aoc(day22, part2)]
+fn solve_part_2(decks: &(Vec<u64>, Vec<u64>)) -> u64 {
+    let mut game = CrabCombat::new(&decks.0, &decks.1, true);
+    game.calculate_winning_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d22_p2_002() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day22_test_002.txt").unwrap(),
+        )
+        .unwrap();
+        let mut game = CrabCombat::new(&input.0, &input.1, true);
+        assert_eq!(CombatPlayer::Player1, game.play());
+    }
+
+    #[test]
+    fn test_d22_p2_001_log() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day22_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        let mut game = CrabCombat::new(&input.0, &input.1, true);
+        game.enable_log();
+        game.play();
+        let log = game.get_log();
+        assert_eq!("=== Game 1 ===", log[0]);
+        assert!(log.contains(&String::from("=== Game 5 ===")));
+        assert!(log.contains(&String::from("The winner of game 1 is player 2!")));
+        assert_eq!("Player 2's deck: 7, 5, 6, 2, 4, 1, 10, 8, 9, 3", log[log.len() - 1]);
+    }
+}
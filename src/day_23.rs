@@ -0,0 +1,122 @@
+use super::error::ParseError;
+use super::utils::collections::CircularList;
+
+/// Solution for AOC 2020 Day 23 (Crab Cups).
+pub struct Day23;
+
+impl_solution!(Day23, day = 23, input = Vec<usize>);
+
+#[aoc_generator(day23)]
+fn generate_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut cups: Vec<usize> = vec![];
+    let (i, raw_line) = match input.lines().enumerate().find(|(_, x)| !x.trim().is_empty()) {
+        Some(first_line) => first_line,
+        None => return Err(ParseError::at_end(23, input, "expected cup labels")),
+    };
+    let line = raw_line.trim();
+    for (j, c) in line.char_indices() {
+        let text = &line[j..j + c.len_utf8()];
+        match c.to_digit(10) {
+            Some(label) if label > 0 => {
+                if cups.contains(&(label as usize)) {
+                    return Err(ParseError::at_text(23, i, raw_line, text, "repeated cup label"));
+                }
+                cups.push(label as usize);
+            },
+            _ => return Err(ParseError::at_text(23, i, raw_line, text, "bad cup label")),
+        }
+    }
+    // At least four cups are needed for the three picked up cups to exclude the current cup
+    if cups.len() < 4 {
+        return Err(ParseError::at_line(23, i, raw_line, "expected at least 4 cup labels"));
+    }
+    // Labels are unique, so they run from 1 up to the number of cups if none exceeds that number
+    if let Some(j) = line.chars().position(|c| c.to_digit(10).unwrap() as usize > cups.len()) {
+        let message = format!("cup labels must run from 1 to {}", cups.len());
+        return Err(ParseError::at_text(23, i, raw_line, &line[j..j + 1], &message));
+    }
+    Ok(cups)
+}
+
+#[aoc(day23, part1)]
+fn solve_part_1(cups: &[usize]) -> String {
+    let cup_circle = play_crab_cups(cups, cups.len(), 100);
+    // Record the labels of the cups after cup 1, excluding cup 1 itself
+    cup_circle
+        .iter_from(1)
+        .skip(1)
+        .map(|label| label.to_string())
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+#[aoc(day23, part2)]
+fn solve_part_2(cups: &[usize]) -> u64 {
+    let cup_circle = play_crab_cups(cups, 1000000, 10000000);
+    let first = cup_circle.get_next(1).unwrap();
+    let second = cup_circle.get_next(first).unwrap();
+    first as u64 * second as u64
+}
+
+/// Plays the crab's game of cups for the given number of moves. The given cups are placed first
+/// in the circle, followed by further cups in ascending order of label until the total number of
+/// cups is reached. Returns the resulting circle of cups.
+fn play_crab_cups(cups: &[usize], total_cups: usize, moves: usize) -> CircularList {
+    let mut labels = cups.to_vec();
+    let start = labels.iter().max().unwrap() + 1;
+    labels.extend(start..=total_cups);
+    let max_label = *labels.iter().max().unwrap();
+    let mut cup_circle = match CircularList::from_values(&labels) {
+        Some(cup_circle) => cup_circle,
+        None => panic!("Day 23 - cup labels are not unique!"),
+    };
+    let mut current = labels[0];
+    for _ in 0..moves {
+        // Pick up the three cups directly clockwise of the current cup
+        let picked_1 = cup_circle.get_next(current).unwrap();
+        let picked_2 = cup_circle.get_next(picked_1).unwrap();
+        let picked_3 = cup_circle.get_next(picked_2).unwrap();
+        // Select destination cup, wrapping around to highest label and skipping picked up cups
+        let mut destination = current;
+        loop {
+            destination = if destination <= 1 { max_label } else { destination - 1 };
+            if destination != picked_1 && destination != picked_2 && destination != picked_3 {
+                break;
+            }
+        }
+        // Place picked up cups directly clockwise of the destination cup
+        if !cup_circle.splice_after(current, 3, destination) {
+            panic!("Day 23 - failed to move picked up cups after cup {}!", destination);
+        }
+        current = cup_circle.get_next(current).unwrap();
+    }
+    cup_circle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d23_input_repeated_label() {
+        let error = generate_input("3892467251").unwrap_err();
+        assert_eq!("repeated cup label", error.get_message());
+        assert_eq!(8, error.get_column());
+    }
+
+    #[test]
+    fn test_d23_input_labels_out_of_range() {
+        let error = generate_input("139").unwrap_err();
+        assert_eq!("expected at least 4 cup labels", error.get_message());
+        let error = generate_input("1394").unwrap_err();
+        assert_eq!("cup labels must run from 1 to 4", error.get_message());
+        assert_eq!(3, error.get_column());
+    }
+
+    #[test]
+    fn test_d23_input_too_few_cups() {
+        let error = generate_input("213").unwrap_err();
+        assert_eq!("expected at least 4 cup labels", error.get_message());
+        assert_eq!(vec![2, 1, 4, 3], generate_input("2143").unwrap());
+    }
+}
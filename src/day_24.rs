@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use super::error::ParseError;
+use super::utils::map::HexDirection;
+use super::utils::map::HexPoint;
+
+/// Solution for AOC 2020 Day 24 (Lobby Layout).
+pub struct Day24;
+
+impl_solution!(Day24, day = 24, input = Vec<Vec<HexDirection>>);
+
+#[aoc_generator(day24)]
+fn generate_input(input: &str) -> Result<Vec<Vec<HexDirection>>, ParseError> {
+    let mut tile_paths: Vec<Vec<HexDirection>> = vec![];
+    for (i, raw_line) in input.lines().enumerate() {
+        // Remove leading and trailing whitespace, then ignore empty lines
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match HexDirection::parse_directions(line) {
+            Some(directions) => tile_paths.push(directions),
+            None => return Err(ParseError::at_line(24, i, raw_line, "malformed tile path")),
+        }
+    }
+    Ok(tile_paths)
+}
+
+#[aoc(day24, part1)]
+fn solve_part_1(tile_paths: &[Vec<HexDirection>]) -> usize {
+    let black_tiles = determine_initial_black_tiles(tile_paths);
+    black_tiles.len()
+}
+
+#[aoc(day24, part2)]
+fn solve_part_2(tile_paths: &[Vec<HexDirection>]) -> usize {
+    let mut black_tiles = determine_initial_black_tiles(tile_paths);
+    for _ in 0..100 {
+        black_tiles = conduct_daily_flip(&black_tiles);
+    }
+    black_tiles.len()
+}
+
+/// Determines the locations of the black tiles after each tile specified by the given paths has
+/// been flipped, with all tiles starting white and paths starting at the reference tile.
+fn determine_initial_black_tiles(tile_paths: &[Vec<HexDirection>]) -> HashSet<HexPoint> {
+    let mut black_tiles: HashSet<HexPoint> = HashSet::new();
+    for path in tile_paths.iter() {
+        let mut loc = HexPoint::new(0, 0);
+        for direction in path.iter() {
+            loc.move_point(*direction);
+        }
+        // Flip the tile at the end of the path
+        if !black_tiles.remove(&loc) {
+            black_tiles.insert(loc);
+        }
+    }
+    black_tiles
+}
+
+/// Determines the black tiles remaining after conducting one day of tile flips. Black tiles with
+/// zero or more than two black neighbours flip to white, and white tiles with exactly two black
+/// neighbours flip to black.
+fn conduct_daily_flip(black_tiles: &HashSet<HexPoint>) -> HashSet<HexPoint> {
+    // Count the number of black neighbours for every tile next to at least one black tile
+    let mut black_neighbour_counts: HashMap<HexPoint, u64> = HashMap::new();
+    for tile in black_tiles.iter() {
+        for neighbour in tile.get_surrounding_points() {
+            *black_neighbour_counts.entry(neighbour).or_insert(0) += 1;
+        }
+    }
+    let mut new_black_tiles: HashSet<HexPoint> = HashSet::new();
+    for (tile, count) in black_neighbour_counts {
+        if count == 2 || (count == 1 && black_tiles.contains(&tile)) {
+            new_black_tiles.insert(tile);
+        }
+    }
+    new_black_tiles
+}
@@ -0,0 +1,60 @@
+use super::error::ParseError;
+use super::utils::math::ntheory::*;
+
+/// Subject number used by the card and door to generate their public keys.
+const PUBLIC_KEY_SUBJECT: i64 = 7;
+/// Modulus used by the handshake transformation.
+const HANDSHAKE_MODULUS: i64 = 20201227;
+
+/// Solution for AOC 2020 Day 25 (Combo Breaker).
+pub struct Day25;
+
+impl_solution!(Day25, day = 25, input = (i64, i64), part_1_only);
+
+#[aoc_generator(day25)]
+fn generate_input(input: &str) -> Result<(i64, i64), ParseError> {
+    let mut public_keys: Vec<i64> = vec![];
+    for (i, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<i64>() {
+            Ok(public_key) => public_keys.push(public_key),
+            Err(_) => return Err(ParseError::at_line(
+                25, i, raw_line, "public key is not a number"
+            )),
+        }
+    }
+    if public_keys.len() != 2 {
+        return Err(ParseError::at_end(25, input, "input must contain exactly two public keys"));
+    }
+    Ok((public_keys[0], public_keys[1]))
+}
+
+#[aoc(day25, part1)]
+fn solve_part_1(public_keys: &(i64, i64)) -> i64 {
+    let (card_public_key, door_public_key) = *public_keys;
+    // Determine the card loop size from its public key, then transform the door public key
+    let card_loop_size = discrete_log(PUBLIC_KEY_SUBJECT, card_public_key, HANDSHAKE_MODULUS);
+    let card_loop_size = match card_loop_size {
+        Some(loop_size) => loop_size,
+        None => panic!("Day 25 Part 1 - could not determine card loop size!"),
+    };
+    mod_pow(door_public_key, card_loop_size, HANDSHAKE_MODULUS).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_d25_p1_001() {
+        let input = generate_input(
+            &std::fs::read_to_string("./input/2020/test/day25_test_001.txt").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(Some(8), discrete_log(PUBLIC_KEY_SUBJECT, input.0, HANDSHAKE_MODULUS));
+        assert_eq!(Some(11), discrete_log(PUBLIC_KEY_SUBJECT, input.1, HANDSHAKE_MODULUS));
+    }
+}
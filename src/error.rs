@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+
+/// Represents a failure to parse the puzzle input for a day, recording where in the input the
+/// failure occurred. Line and column numbers start at 1, with columns counted in characters from
+/// the start of the untrimmed line.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    day: u32,
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Creates a new parse error for the given day, occurring at the given line and column.
+    pub fn new(day: u32, line: usize, column: usize, text: &str, message: &str) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates a new parse error for offending text found within the given line of input. The
+    /// line index is zero-based (as given by enumerating over the input lines). The column is
+    /// determined from the location of the offending text within the line, with the offending
+    /// text ideally being a slice of the line itself.
+    pub fn at_text(day: u32, line_index: usize, line: &str, text: &str, message: &str) -> Self {
+        let column = locate_column(line, text);
+        ParseError::new(day, line_index + 1, column, text, message)
+    }
+
+    /// Creates a new parse error covering the whole of the given line of input. The line index is
+    /// zero-based (as given by enumerating over the input lines).
+    pub fn at_line(day: u32, line_index: usize, line: &str, message: &str) -> Self {
+        ParseError::at_text(day, line_index, line, line.trim(), message)
+    }
+
+    /// Creates a new parse error for input that ended before all expected content was found. The
+    /// error is located at the start of the line following the last line of the input.
+    pub fn at_end(day: u32, input: &str, message: &str) -> Self {
+        ParseError::new(day, input.lines().count() + 1, 1, "", message)
+    }
+
+    /// Gets the day of the puzzle for which the input was being parsed.
+    pub fn get_day(&self) -> u32 {
+        self.day
+    }
+
+    /// Gets the line number (starting at 1) at which parsing failed.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the column number (starting at 1) at which parsing failed.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// Gets the offending text that could not be parsed.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the message describing why parsing failed.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} - line {}, column {}: {} ---- \"{}\"",
+            self.day, self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Represents a failure to run the solution for one part of a puzzle against the given input.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub enum SolutionError {
+    /// The input could not be parsed.
+    Parse(ParseError),
+    /// The puzzle does not have the part requested (given by day and part).
+    MissingPart(u32, u32),
+}
+
+impl fmt::Display for SolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolutionError::Parse(e) => write!(f, "failed to parse input: {}", e),
+            SolutionError::MissingPart(day, part) => {
+                write!(f, "Day {} - Part {}: puzzle does not have this part", day, part)
+            },
+        }
+    }
+}
+
+impl Error for SolutionError {}
+
+impl From<ParseError> for SolutionError {
+    fn from(error: ParseError) -> Self {
+        SolutionError::Parse(error)
+    }
+}
+
+/// Determines the column (starting at 1, counted in characters) at which the given text starts
+/// within the line. If the text is a slice of the line, its exact location is used. Otherwise, the
+/// first occurrence of the text is used, falling back to the first non-whitespace character.
+fn locate_column(line: &str, text: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let text_start = text.as_ptr() as usize;
+    let byte_offset = if text_start >= line_start
+        && text_start + text.len() <= line_start + line.len()
+        && line.is_char_boundary(text_start - line_start)
+    {
+        text_start - line_start
+    } else if let Some(offset) = line.find(text).filter(|_| !text.is_empty()) {
+        offset
+    } else {
+        line.len() - line.trim_start().len()
+    };
+    line[..byte_offset].chars().count() + 1
+}
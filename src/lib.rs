@@ -1,6 +1,11 @@
 #[macro_use]
 extern crate aoc_runner_derive;
 
+pub mod benchmark;
+pub mod error;
+pub mod registry;
+#[macro_use]
+pub mod solution;
 pub mod utils;
 
 // Modules for day solutions
@@ -23,7 +28,11 @@ pub mod day_16;
 pub mod day_17;
 pub mod day_18;
 pub mod day_19;
-// pub mod day_20;
+pub mod day_20;
 pub mod day_21;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
 
 aoc_lib!{ year = 2020 }
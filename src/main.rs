@@ -0,0 +1,294 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use advent_of_code_2020::benchmark;
+use advent_of_code_2020::benchmark::BenchmarkReport;
+use advent_of_code_2020::day_11;
+use advent_of_code_2020::day_11::Day11;
+use advent_of_code_2020::day_17;
+use advent_of_code_2020::day_17::Day17;
+use advent_of_code_2020::registry::SolutionRegistry;
+use advent_of_code_2020::solution::Solution;
+use advent_of_code_2020::utils::render::FrameFormat;
+use advent_of_code_2020::utils::render::FrameWriter;
+
+/// Year of the puzzles solved by this crate.
+const YEAR: u32 = 2020;
+
+const USAGE: &str = "\
+Usage: aoc2020 run --day <DAY> --part <PART> [--input <PATH>]
+       aoc2020 bench [--day <DAY>] [--part <PART>] [--runs <RUNS>] [--input-dir <DIR>]
+                     [--output <PATH>]
+       aoc2020 render --day <DAY> --part <PART> --output-dir <DIR> [--input <PATH>]
+                      [--format <FORMAT>]
+       aoc2020 list
+
+run    Runs the solution for the given day (1-25) and part (1-2) against the puzzle input read
+       from the given file. Input is read from stdin if no input file is given, or if the path is
+       \"-\".
+bench  Runs the generator and solver for each solution (or only the given day and part) the given
+       number of times (default 10), reporting the min, median and max time taken by each. Input
+       for day N is read from \"dayN.txt\" in the given directory (default \"input/2020\"), with
+       days lacking an input file skipped. A JSON report is written to the given file, or to
+       stdout if no output file is given.
+render Writes a numbered frame file to the given directory for each step of the simulation run by
+       the solution for the given day and part, for assembling into an animation. Supported for
+       days 11 and 17. The format is \"ascii\" (default), \"ppm\" or \"pgm\", with day 17 only
+       supporting \"ascii\". Input is read as for \"run\".
+list   Lists the day and part of every available solution.";
+
+/// Default number of times each solution is run when benchmarking.
+const DEFAULT_BENCH_RUNS: usize = 10;
+/// Default directory containing the puzzle input files used when benchmarking.
+const DEFAULT_INPUT_DIR: &str = "input/2020";
+
+/// Represents a command given to the runner on the command line.
+enum Command {
+    Run { day: u32, part: u32, input_path: Option<String> },
+    Bench {
+        day: Option<u32>,
+        part: Option<u32>,
+        runs: usize,
+        input_dir: String,
+        output_path: Option<String>,
+    },
+    Render {
+        day: u32,
+        part: u32,
+        input_path: Option<String>,
+        output_dir: String,
+        format: FrameFormat,
+    },
+    List,
+    Help,
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("Error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+    let result = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+        Command::List => {
+            let registry = SolutionRegistry::with_all_solutions();
+            for entry in registry.iter() {
+                println!("Day {} - Part {}", entry.get_day(), entry.get_part());
+            }
+            Ok(())
+        },
+        Command::Run { day, part, input_path } => run_solution(day, part, input_path),
+        Command::Bench { day, part, runs, input_dir, output_path } => {
+            run_benchmarks(day, part, runs, &input_dir, output_path)
+        },
+        Command::Render { day, part, input_path, output_dir, format } => {
+            render_frames(day, part, input_path, &output_dir, format)
+        },
+    };
+    if let Err(message) = result {
+        eprintln!("Error: {}", message);
+        process::exit(1);
+    }
+}
+
+/// Parses the command line arguments (excluding the program name) into a command.
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(|x| x.as_str()) {
+        None | Some("help") | Some("-h") | Some("--help") => return Ok(Command::Help),
+        Some("list") => return Ok(Command::List),
+        Some(command @ "run") | Some(command @ "bench") | Some(command @ "render") => command,
+        Some(other) => return Err(format!("unknown command \"{}\"", other)),
+    };
+    let mut day: Option<u32> = None;
+    let mut part: Option<u32> = None;
+    let mut input_path: Option<String> = None;
+    let mut runs: Option<usize> = None;
+    let mut input_dir: Option<String> = None;
+    let mut output_path: Option<String> = None;
+    let mut output_dir: Option<String> = None;
+    let mut format: Option<FrameFormat> = None;
+    while let Some(arg) = args.next() {
+        let value = match args.next() {
+            Some(value) => value,
+            None => return Err(format!("missing value for \"{}\"", arg)),
+        };
+        match (command, arg.as_str()) {
+            (_, "-d") | (_, "--day") => day = Some(parse_number(arg, value)?),
+            (_, "-p") | (_, "--part") => part = Some(parse_number(arg, value)?),
+            ("run", "-i") | ("run", "--input") | ("render", "-i") | ("render", "--input") => {
+                input_path = Some(value.to_string());
+            },
+            ("bench", "-n") | ("bench", "--runs") => {
+                runs = Some(parse_number(arg, value)? as usize);
+            },
+            ("bench", "--input-dir") => input_dir = Some(value.to_string()),
+            ("bench", "-o") | ("bench", "--output") => output_path = Some(value.to_string()),
+            ("render", "-o") | ("render", "--output-dir") => output_dir = Some(value.to_string()),
+            ("render", "-f") | ("render", "--format") => match FrameFormat::from_name(value) {
+                Some(value) => format = Some(value),
+                None => return Err(format!("invalid value \"{}\" for \"{}\"", value, arg)),
+            },
+            _ => return Err(format!("unknown option \"{}\"", arg)),
+        }
+    }
+    if day.is_some_and(|day| !(1..=25).contains(&day)) {
+        return Err(format!("day {} is out of range", day.unwrap()));
+    }
+    if part.is_some_and(|part| !(1..=2).contains(&part)) {
+        return Err(format!("part {} is out of range", part.unwrap()));
+    }
+    if command == "bench" {
+        return Ok(Command::Bench {
+            day,
+            part,
+            runs: runs.unwrap_or(DEFAULT_BENCH_RUNS),
+            input_dir: input_dir.unwrap_or_else(|| DEFAULT_INPUT_DIR.to_string()),
+            output_path,
+        });
+    }
+    let day = day.ok_or("missing required option \"--day\"")?;
+    let part = part.ok_or("missing required option \"--part\"")?;
+    if command == "render" {
+        return Ok(Command::Render {
+            day,
+            part,
+            input_path,
+            output_dir: output_dir.ok_or("missing required option \"--output-dir\"")?,
+            format: format.unwrap_or(FrameFormat::Ascii),
+        });
+    }
+    Ok(Command::Run { day, part, input_path })
+}
+
+/// Parses the value given for a numeric command line option.
+fn parse_number(option: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .map_err(|_| format!("invalid value \"{}\" for \"{}\"", value, option))
+}
+
+/// Reads the puzzle input, then runs the generator and solver for the given day and part,
+/// printing the answer along with the time taken by each.
+fn run_solution(day: u32, part: u32, input_path: Option<String>) -> Result<(), String> {
+    let registry = SolutionRegistry::with_all_solutions();
+    let entry = match registry.get(YEAR, day, part) {
+        Some(entry) => entry,
+        None => return Err(format!("Day {} - Part {}: no solution implemented", day, part)),
+    };
+    let input = read_input(input_path)?;
+    let run = entry.run(&input).map_err(|e| e.to_string())?;
+    println!(
+        "Day {} - Part {}: {}\n\tgenerator: {:?},\n\trunner: {:?}",
+        day, part, run.answer, run.generator_time, run.solver_time
+    );
+    Ok(())
+}
+
+/// Benchmarks the generator and solver for each registered solution (or only the given day and
+/// part), printing a summary of the times taken as each completes. The JSON report is written to
+/// the given output file, or to stdout if no output file is given.
+fn run_benchmarks(day: Option<u32>, part: Option<u32>, runs: usize, input_dir: &str,
+        output_path: Option<String>) -> Result<(), String>
+{
+    let registry = SolutionRegistry::with_all_solutions();
+    let mut report = BenchmarkReport::new(YEAR);
+    let entries = registry
+        .iter()
+        .filter(|x| x.get_year() == YEAR)
+        .filter(|x| day.is_none_or(|day| x.get_day() == day))
+        .filter(|x| part.is_none_or(|part| x.get_part() == part));
+    for entry in entries {
+        let input_path = Path::new(input_dir).join(format!("day{}.txt", entry.get_day()));
+        if !input_path.is_file() {
+            eprintln!(
+                "Day {} - Part {}: skipped, no input file at \"{}\"",
+                entry.get_day(), entry.get_part(), input_path.display()
+            );
+            continue;
+        }
+        let input = read_input(Some(input_path.display().to_string()))?;
+        let result = benchmark::run_benchmark(entry, &input, runs)
+            .map_err(|e| e.to_string())?;
+        eprintln!(
+            "Day {} - Part {} ({} runs)\n\tgenerator: min {:?}, median {:?}, max {:?}\n\t\
+            runner: min {:?}, median {:?}, max {:?}",
+            result.day, result.part, result.runs,
+            Duration::from_nanos(result.generator_ns.min),
+            Duration::from_nanos(result.generator_ns.median),
+            Duration::from_nanos(result.generator_ns.max),
+            Duration::from_nanos(result.solver_ns.min),
+            Duration::from_nanos(result.solver_ns.median),
+            Duration::from_nanos(result.solver_ns.max),
+        );
+        report.add_result(result);
+    }
+    match output_path {
+        Some(path) => {
+            fs::write(&path, report.to_json() + "\n")
+                .map_err(|e| format!("failed to write report file \"{}\": {}", path, e))?;
+        },
+        None => println!("{}", report.to_json()),
+    }
+    Ok(())
+}
+
+/// Reads the puzzle input, then writes the frames of the simulation run by the solution for the
+/// given day and part to the output directory in the given format.
+fn render_frames(day: u32, part: u32, input_path: Option<String>, output_dir: &str,
+        format: FrameFormat) -> Result<(), String>
+{
+    let input = read_input(input_path)?;
+    let prefix = format!("day{}_part{}", day, part);
+    let mut writer = FrameWriter::new(Path::new(output_dir), &prefix, format)
+        .map_err(|e| format!("failed to create output directory \"{}\": {}", output_dir, e))?;
+    let result = match day {
+        11 => {
+            let seating_map = Day11::parse_input(&input)
+                .map_err(|e| format!("failed to parse input: {}", e))?;
+            day_11::write_frames(&seating_map, part, &mut writer)
+        },
+        17 => {
+            let input = Day17::parse_input(&input)
+                .map_err(|e| format!("failed to parse input: {}", e))?;
+            day_17::write_frames(&input, part, &mut writer)
+        },
+        _ => return Err(format!("Day {} - Part {}: rendering not supported", day, part)),
+    };
+    result.map_err(|e| format!("failed to write frames: {}", e))?;
+    eprintln!(
+        "Day {} - Part {}: wrote {} frames to \"{}\"",
+        day, part, writer.get_frame_count(), output_dir
+    );
+    Ok(())
+}
+
+/// Reads the puzzle input from the given file, or from stdin if no file (or "-") is given.
+/// Trailing newlines are removed from the input, consistent with inputs given by cargo-aoc.
+fn read_input(input_path: Option<String>) -> Result<String, String> {
+    let mut input = match input_path.as_deref() {
+        None | Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("failed to read input from stdin: {}", e))?;
+            input
+        },
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("failed to read input file \"{}\": {}", path, e))?,
+    };
+    input.truncate(input.trim_end_matches('\n').len());
+    Ok(input)
+}
@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use super::error::SolutionError;
+use super::solution::Answer;
+use super::solution::Solution;
+
+/// Records the answer produced by running a solution, along with the time taken to parse the input
+/// and the time taken to solve the puzzle part.
+#[derive(Clone, Debug)]
+pub struct SolutionRun {
+    pub answer: Answer,
+    pub generator_time: Duration,
+    pub solver_time: Duration,
+}
+
+/// Function that parses raw input and solves a single puzzle part.
+type SolutionRunner = fn(&str) -> Result<SolutionRun, SolutionError>;
+
+/// Represents a single registered puzzle part, able to run its solution against raw input.
+#[derive(Copy, Clone)]
+pub struct SolutionEntry {
+    year: u32,
+    day: u32,
+    part: u32,
+    runner: SolutionRunner,
+}
+
+impl SolutionEntry {
+    /// Gets the year of the puzzle.
+    pub fn get_year(&self) -> u32 {
+        self.year
+    }
+
+    /// Gets the day of the puzzle.
+    pub fn get_day(&self) -> u32 {
+        self.day
+    }
+
+    /// Gets the puzzle part (1 or 2).
+    pub fn get_part(&self) -> u32 {
+        self.part
+    }
+
+    /// Parses the raw input and solves the puzzle part, timing both steps. Returns an error if the
+    /// input could not be parsed or the puzzle does not have the part.
+    pub fn run(&self, input: &str) -> Result<SolutionRun, SolutionError> {
+        (self.runner)(input)
+    }
+}
+
+/// Registry of puzzle solutions, keyed by (year, day, part). Entries are kept in order, so
+/// iterating over the registry visits each year, day and part in turn.
+pub struct SolutionRegistry {
+    entries: BTreeMap<(u32, u32, u32), SolutionEntry>,
+}
+
+impl SolutionRegistry {
+    /// Creates a new registry with no solutions registered.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new registry containing the solutions for every day of AOC 2020.
+    pub fn with_all_solutions() -> Self {
+        let mut registry = SolutionRegistry::new();
+        registry.register::<super::day_01::Day01>();
+        registry.register::<super::day_02::Day02>();
+        registry.register::<super::day_03::Day03>();
+        registry.register::<super::day_04::Day04>();
+        registry.register::<super::day_05::Day05>();
+        registry.register::<super::day_06::Day06>();
+        registry.register::<super::day_07::Day07>();
+        registry.register::<super::day_08::Day08>();
+        registry.register::<super::day_09::Day09>();
+        registry.register::<super::day_10::Day10>();
+        registry.register::<super::day_11::Day11>();
+        registry.register::<super::day_12::Day12>();
+        registry.register::<super::day_13::Day13>();
+        registry.register::<super::day_14::Day14>();
+        registry.register::<super::day_15::Day15>();
+        registry.register::<super::day_16::Day16>();
+        registry.register::<super::day_17::Day17>();
+        registry.register::<super::day_18::Day18>();
+        registry.register::<super::day_19::Day19>();
+        registry.register::<super::day_20::Day20>();
+        registry.register::<super::day_21::Day21>();
+        registry.register::<super::day_22::Day22>();
+        registry.register::<super::day_23::Day23>();
+        registry.register::<super::day_24::Day24>();
+        registry.register::<super::day_25::Day25>();
+        registry
+    }
+
+    /// Registers the parts of the given solution. Part 2 is only registered if the solution has a
+    /// second part.
+    pub fn register<S: Solution>(&mut self) {
+        self.insert(S::YEAR, S::DAY, 1, run_part_1::<S>);
+        if S::HAS_PART_2 {
+            self.insert(S::YEAR, S::DAY, 2, run_part_2::<S>);
+        }
+    }
+
+    /// Gets the entry for the given year, day and part, if a solution is registered.
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&SolutionEntry> {
+        self.entries.get(&(year, day, part))
+    }
+
+    /// Gets an iterator over all registered entries, in order of year, day and part.
+    pub fn iter(&self) -> impl Iterator<Item = &SolutionEntry> {
+        self.entries.values()
+    }
+
+    /// Gets the number of registered entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the registry has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a new entry into the registry, replacing any existing entry for the same part.
+    fn insert(&mut self, year: u32, day: u32, part: u32, runner: SolutionRunner) {
+        let entry = SolutionEntry {
+            year,
+            day,
+            part,
+            runner,
+        };
+        self.entries.insert((year, day, part), entry);
+    }
+}
+
+impl Default for SolutionRegistry {
+    fn default() -> Self {
+        SolutionRegistry::new()
+    }
+}
+
+/// Parses the input and solves part 1 of the given solution, timing both steps.
+fn run_part_1<S: Solution>(input: &str) -> Result<SolutionRun, SolutionError> {
+    let start_time = Instant::now();
+    let parsed = S::parse_input(input)?;
+    let inter_time = Instant::now();
+    let answer = S::part_1(&parsed);
+    let final_time = Instant::now();
+    Ok(SolutionRun {
+        answer,
+        generator_time: inter_time - start_time,
+        solver_time: final_time - inter_time,
+    })
+}
+
+/// Parses the input and solves part 2 of the given solution, timing both steps. Returns an error if
+/// the solution does not have a second part.
+fn run_part_2<S: Solution>(input: &str) -> Result<SolutionRun, SolutionError> {
+    let start_time = Instant::now();
+    let parsed = S::parse_input(input)?;
+    let inter_time = Instant::now();
+    let answer = S::part_2(&parsed).ok_or(SolutionError::MissingPart(S::DAY, 2))?;
+    let final_time = Instant::now();
+    Ok(SolutionRun {
+        answer,
+        generator_time: inter_time - start_time,
+        solver_time: final_time - inter_time,
+    })
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+use super::error::ParseError;
+
+/// Represents the answer to one part of a puzzle. Answers are either integers (signed or unsigned)
+/// or text, and are displayed the same way as the underlying value.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Common interface implemented by the solution for each day. The raw puzzle input is parsed
+/// once into the associated input type, which is then shared by the solvers for each part.
+pub trait Solution {
+    /// Parsed form of the puzzle input.
+    type Input;
+
+    /// Year of the event that the puzzle belongs to.
+    const YEAR: u32 = 2020;
+    /// Day of the event that the puzzle belongs to.
+    const DAY: u32;
+    /// Whether the puzzle has a second part to be solved (false only for Day 25).
+    const HAS_PART_2: bool = true;
+
+    /// Parses the raw puzzle input. Returns an error locating the offending input if it is
+    /// malformed.
+    fn parse_input(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves part 1 of the puzzle using the parsed input.
+    fn part_1(input: &Self::Input) -> Answer;
+
+    /// Solves part 2 of the puzzle using the parsed input. Returns None if the puzzle does not have
+    /// a second part to be solved.
+    fn part_2(input: &Self::Input) -> Option<Answer>;
+}
+
+/// Implements the Solution trait for the solution type of a day, forwarding to the input generator
+/// and part solvers also used by cargo-aoc ("generate_input", "solve_part_1" and "solve_part_2"),
+/// which must be in scope where the macro is used. Days without a second part to be solved are
+/// marked as "part_1_only", and do not need a "solve_part_2" function.
+macro_rules! impl_solution {
+    ($solution:ident, day = $day:expr, input = $input:ty) => {
+        impl $crate::solution::Solution for $solution {
+            type Input = $input;
+
+            const DAY: u32 = $day;
+
+            fn parse_input(input: &str) -> Result<Self::Input, $crate::error::ParseError> {
+                return generate_input(input);
+            }
+
+            fn part_1(input: &Self::Input) -> $crate::solution::Answer {
+                return $crate::solution::Answer::from(solve_part_1(input));
+            }
+
+            fn part_2(input: &Self::Input) -> Option<$crate::solution::Answer> {
+                return Some($crate::solution::Answer::from(solve_part_2(input)));
+            }
+        }
+    };
+    ($solution:ident, day = $day:expr, input = $input:ty, part_1_only) => {
+        impl $crate::solution::Solution for $solution {
+            type Input = $input;
+
+            const DAY: u32 = $day;
+            const HAS_PART_2: bool = false;
+
+            fn parse_input(input: &str) -> Result<Self::Input, $crate::error::ParseError> {
+                return generate_input(input);
+            }
+
+            fn part_1(input: &Self::Input) -> $crate::solution::Answer {
+                return $crate::solution::Answer::from(solve_part_1(input));
+            }
+
+            fn part_2(_input: &Self::Input) -> Option<$crate::solution::Answer> {
+                return None;
+            }
+        }
+    };
+}
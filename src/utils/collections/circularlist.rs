@@ -0,0 +1,259 @@
+/// Marker used in the successor array for values not currently held in the list.
+const ABSENT: u32 = u32::MAX;
+
+/// Represents a singly-linked circular list of distinct values in the range [0, capacity). The
+/// list is backed by a successor array indexed by value, so finding, inserting and removing
+/// values are all constant-time operations that do not allocate. Successors are stored as 32-bit
+/// values to keep the array compact, so the capacity cannot exceed u32::MAX.
+#[derive(Clone, Debug)]
+pub struct CircularList {
+    next: Vec<u32>,
+    len: usize,
+}
+
+impl CircularList {
+    /// Creates a new empty list able to hold values in the range [0, capacity). Panics if the
+    /// capacity exceeds u32::MAX.
+    pub fn new(capacity: usize) -> Self {
+        if capacity > ABSENT as usize {
+            panic!("CircularList - capacity exceeds u32::MAX!");
+        }
+        Self {
+            next: vec![ABSENT; capacity],
+            len: 0,
+        }
+    }
+
+    /// Creates a new list containing the given values in order, with the last value linked back
+    /// around to the first. The capacity of the list is one more than the largest value. Returns
+    /// None if any value occurs more than once.
+    pub fn from_values(values: &[usize]) -> Option<Self> {
+        let capacity = match values.iter().max() {
+            Some(max) => max + 1,
+            None => 0,
+        };
+        let mut list = CircularList::new(capacity);
+        let mut last: Option<usize> = None;
+        for &value in values {
+            match last {
+                None => {
+                    list.next[value] = value as u32;
+                    list.len = 1;
+                }
+                Some(prev) => {
+                    if !list.insert_after(prev, value) {
+                        return None;
+                    }
+                }
+            }
+            last = Some(value);
+        }
+        Some(list)
+    }
+
+    /// Gets the number of values held in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the list holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the capacity of the list, being one more than the largest value it can hold.
+    pub fn capacity(&self) -> usize {
+        self.next.len()
+    }
+
+    /// Checks if the given value is held in the list.
+    pub fn contains(&self, value: usize) -> bool {
+        value < self.next.len() && self.next[value] != ABSENT
+    }
+
+    /// Gets the value following the given value in the list. Returns None if the given value is
+    /// not held in the list.
+    pub fn get_next(&self, value: usize) -> Option<usize> {
+        if !self.contains(value) {
+            return None;
+        }
+        Some(self.next[value] as usize)
+    }
+
+    /// Inserts the new value into the list directly after the given node. If the list is empty, the
+    /// new value becomes the only value in the list and the node is ignored. Returns false (and
+    /// leaves the list unchanged) if the node is not in the list, or if the new value is already
+    /// held in the list or exceeds its capacity.
+    pub fn insert_after(&mut self, node: usize, value: usize) -> bool {
+        if value >= self.next.len() || self.contains(value) {
+            return false;
+        }
+        if self.is_empty() {
+            self.next[value] = value as u32;
+            self.len = 1;
+            return true;
+        }
+        if !self.contains(node) {
+            return false;
+        }
+        self.next[value] = self.next[node];
+        self.next[node] = value as u32;
+        self.len += 1;
+        true
+    }
+
+    /// Removes the value directly following the given node from the list, returning the removed
+    /// value. Returns None if the node is not in the list.
+    pub fn remove_after(&mut self, node: usize) -> Option<usize> {
+        let removed = self.get_next(node)?;
+        if removed == node {
+            self.len = 0;
+        } else {
+            self.next[node] = self.next[removed];
+            self.len -= 1;
+        }
+        self.next[removed] = ABSENT;
+        Some(removed)
+    }
+
+    /// Moves the run of values directly following the source node to directly follow the
+    /// destination node, preserving their order. Returns false (and leaves the list unchanged) if
+    /// either node is not in the list, the run would include the source node itself or if the
+    /// destination node is part of the run being moved.
+    pub fn splice_after(&mut self, source: usize, count: usize, destination: usize) -> bool {
+        if !self.contains(source) || !self.contains(destination) || count >= self.len {
+            return false;
+        }
+        if count == 0 || source == destination {
+            return true;
+        }
+        // Find the first and last values in the run, checking destination is not being moved
+        let first = self.next[source] as usize;
+        let mut last = first;
+        for i in 0..count {
+            if last == destination {
+                return false;
+            }
+            if i + 1 < count {
+                last = self.next[last] as usize;
+            }
+        }
+        // Unlink the run from after the source and relink it after the destination
+        self.next[source] = self.next[last];
+        self.next[last] = self.next[destination];
+        self.next[destination] = first as u32;
+        true
+    }
+
+    /// Gets an iterator over all values in the list, starting at the given value and following
+    /// the list around once. The iterator is empty if the start value is not in the list.
+    pub fn iter_from(&self, start: usize) -> CircularListIter<'_> {
+        let remaining = if self.contains(start) { self.len } else { 0 };
+        CircularListIter {
+            list: self,
+            current: start,
+            remaining,
+        }
+    }
+}
+
+/// Iterator over the values held in a circular list, going once around the list.
+pub struct CircularListIter<'a> {
+    list: &'a CircularList,
+    current: usize,
+    remaining: usize,
+}
+
+impl Iterator for CircularListIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let value = self.current;
+        self.current = self.list.next[value] as usize;
+        self.remaining -= 1;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values_from(list: &CircularList, start: usize) -> Vec<usize> {
+        list.iter_from(start).collect::<Vec<usize>>()
+    }
+
+    #[test]
+    fn test_from_values() {
+        let list = CircularList::from_values(&[3, 8, 9, 1, 2, 5, 4, 6, 7]).unwrap();
+        assert_eq!(9, list.len());
+        assert_eq!(10, list.capacity());
+        assert!(!list.contains(0));
+        assert_eq!(Some(3), list.get_next(7));
+        assert_eq!(None, list.get_next(0));
+        assert_eq!(vec![1, 2, 5, 4, 6, 7, 3, 8, 9], values_from(&list, 1));
+        assert!(list.iter_from(0).next().is_none());
+        assert!(CircularList::from_values(&[1, 2, 1]).is_none());
+        assert!(CircularList::from_values(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let mut list = CircularList::new(5);
+        assert!(list.insert_after(0, 2));
+        assert_eq!(Some(2), list.get_next(2));
+        assert!(list.insert_after(2, 4));
+        assert!(list.insert_after(2, 1));
+        assert_eq!(vec![2, 1, 4], values_from(&list, 2));
+        // Repeated values, values beyond capacity and missing nodes are rejected
+        assert!(!list.insert_after(4, 1));
+        assert!(!list.insert_after(4, 5));
+        assert!(!list.insert_after(3, 0));
+        assert_eq!(vec![2, 1, 4], values_from(&list, 2));
+    }
+
+    #[test]
+    fn test_remove_after() {
+        let mut list = CircularList::from_values(&[0, 1, 2]).unwrap();
+        assert_eq!(Some(0), list.remove_after(2));
+        assert_eq!(vec![1, 2], values_from(&list, 1));
+        assert_eq!(None, list.remove_after(0));
+        assert_eq!(Some(2), list.remove_after(1));
+        // Removing the only value empties the list
+        assert_eq!(Some(1), list.remove_after(1));
+        assert!(list.is_empty());
+        assert!(!list.contains(1));
+        assert!(list.insert_after(0, 0));
+        assert_eq!(vec![0], values_from(&list, 0));
+    }
+
+    #[test]
+    fn test_splice_after() {
+        let mut list = CircularList::from_values(&[3, 8, 9, 1, 2, 5, 4, 6, 7]).unwrap();
+        assert!(list.splice_after(3, 3, 2));
+        assert_eq!(vec![3, 2, 8, 9, 1, 5, 4, 6, 7], values_from(&list, 3));
+        // Run wrapping around the end of the list back to its start
+        assert!(list.splice_after(6, 3, 1));
+        assert_eq!(vec![2, 5, 4, 6, 8, 9, 1, 7, 3], values_from(&list, 2));
+        assert!(list.splice_after(1, 0, 4));
+        assert_eq!(vec![2, 5, 4, 6, 8, 9, 1, 7, 3], values_from(&list, 2));
+    }
+
+    #[test]
+    fn test_splice_after_rejected() {
+        let mut list = CircularList::from_values(&[0, 1, 2, 3, 4]).unwrap();
+        // Destination is within the run being moved, at its start, middle and end
+        assert!(!list.splice_after(0, 3, 1));
+        assert!(!list.splice_after(0, 3, 2));
+        assert!(!list.splice_after(0, 3, 3));
+        // Run would include the source itself, or a node is not in the list
+        assert!(!list.splice_after(0, 5, 4));
+        assert!(!list.splice_after(5, 1, 0));
+        assert!(!list.splice_after(0, 1, 7));
+        assert_eq!(vec![0, 1, 2, 3, 4], values_from(&list, 0));
+        assert_eq!(5, list.len());
+    }
+}
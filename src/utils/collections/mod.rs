@@ -0,0 +1,4 @@
+mod circularlist;
+
+pub use self::circularlist::CircularList;
+pub use self::circularlist::CircularListIter;
@@ -0,0 +1,51 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::AutomatonHistory;
+
+/// Represents a cellular automaton that is advanced one step at a time, with a population (the
+/// number of live cells) and a state that can be compared with its earlier states.
+pub trait CellularAutomaton {
+    /// Snapshot of the state of the automaton, with equal snapshots for identical states.
+    type State: Hash + Eq;
+
+    /// Conducts a single step of the automaton.
+    fn conduct_step(&mut self);
+
+    /// Gets the number of steps conducted so far.
+    fn get_steps(&self) -> u64;
+
+    /// Gets the number of live cells in the current state of the automaton.
+    fn get_population(&self) -> u64;
+
+    /// Gets a snapshot of the current state of the automaton.
+    fn get_state(&self) -> Self::State;
+}
+
+/// Conducts steps of the automaton until it repeats an earlier state (reaching a fixed point or a
+/// cycle) or the given number of steps have been conducted, returning the history of the run. The
+/// automaton is left in the state at the end of the run, with the snapshot of every state reached
+/// being kept until then.
+pub fn run_until_repeat<A: CellularAutomaton>(automaton: &mut A, max_steps: u64)
+        -> AutomatonHistory
+{
+    let mut history = AutomatonHistory::new(automaton.get_steps(), automaton.get_population());
+    let mut seen_states: HashMap<A::State, u64> = HashMap::new();
+    seen_states.insert(automaton.get_state(), automaton.get_steps());
+    for _ in 0..max_steps {
+        automaton.conduct_step();
+        history.record(automaton.get_population());
+        let step = automaton.get_steps();
+        match seen_states.entry(automaton.get_state()) {
+            Entry::Occupied(entry) => {
+                history.record_cycle(*entry.get(), step - *entry.get());
+                break;
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+            },
+        }
+    }
+    history
+}
@@ -0,0 +1,89 @@
+/// Represents the run of a cellular automaton until it repeated an earlier state or reached its
+/// step limit, recording the population of the automaton at each step of the run.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct AutomatonHistory {
+    first_step: u64,
+    populations: Vec<u64>,
+    cycle: Option<(u64, u64)>,
+}
+
+impl AutomatonHistory {
+    /// Creates a new history for a run starting at the given step with the given population.
+    pub(super) fn new(first_step: u64, population: u64) -> Self {
+        Self {
+            first_step,
+            populations: vec![population],
+            cycle: None,
+        }
+    }
+
+    /// Records the population of the automaton after the next step of the run.
+    pub(super) fn record(&mut self, population: u64) {
+        self.populations.push(population);
+    }
+
+    /// Records that the automaton has settled into a cycle with the given period, first entering
+    /// the repeated state at the given step.
+    pub(super) fn record_cycle(&mut self, cycle_start: u64, period: u64) {
+        self.cycle = Some((cycle_start, period));
+    }
+
+    /// Gets the step of the automaton at which the run started.
+    pub fn get_first_step(&self) -> u64 {
+        self.first_step
+    }
+
+    /// Gets the step of the automaton at which the run ended.
+    pub fn get_last_step(&self) -> u64 {
+        self.first_step + self.populations.len() as u64 - 1
+    }
+
+    /// Gets the population of the automaton at each step of the run, starting with the population
+    /// at the first step.
+    pub fn get_populations(&self) -> &Vec<u64> {
+        &self.populations
+    }
+
+    /// Gets the population of the automaton at the end of the run.
+    pub fn get_final_population(&self) -> u64 {
+        self.populations[self.populations.len() - 1]
+    }
+
+    /// Gets the population of the automaton at the given step. Populations at steps after the end
+    /// of the run are extrapolated from the cycle the automaton settled into. Returns None if the
+    /// step is before the start of the run, or after the end of a run that reached its step limit.
+    pub fn get_population(&self, step: u64) -> Option<u64> {
+        if step < self.first_step {
+            return None;
+        }
+        if step <= self.get_last_step() {
+            return Some(self.populations[(step - self.first_step) as usize]);
+        }
+        let (cycle_start, period) = self.cycle?;
+        let step = cycle_start + (step - cycle_start) % period;
+        Some(self.populations[(step - self.first_step) as usize])
+    }
+
+    /// Gets the step at which the automaton first entered the state that was later repeated (for a
+    /// fixed point, the step at which the automaton stopped changing). Returns None if the run
+    /// reached its step limit without repeating a state.
+    pub fn get_cycle_start(&self) -> Option<u64> {
+        self.cycle.map(|(cycle_start, _period)| cycle_start)
+    }
+
+    /// Gets the number of steps between repeats of the state of the automaton (1 for a fixed
+    /// point). Returns None if the run reached its step limit without repeating a state.
+    pub fn get_period(&self) -> Option<u64> {
+        self.cycle.map(|(_cycle_start, period)| period)
+    }
+
+    /// Checks if the automaton reached a fixed point, with its state no longer changing.
+    pub fn is_fixed_point(&self) -> bool {
+        self.get_period() == Some(1)
+    }
+
+    /// Checks if the automaton settled into a cycle of two or more distinct states.
+    pub fn is_cycle(&self) -> bool {
+        self.get_period().is_some_and(|period| period > 1)
+    }
+}
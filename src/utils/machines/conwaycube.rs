@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use super::super::map::Aabb;
+use super::super::map::Neighbourhood;
+use super::super::map::Point;
+use super::CellularAutomaton;
+use super::LifeRule;
+
+/// Names of the coordinates of cubes, used when labelling slices of the Conway Cube.
+const COORD_NAMES: [&str; 6] = ["x", "y", "z", "w", "v", "u"];
+/// First of the dimensions in which the Conway Cube is mirror symmetric, being the dimensions
+/// beyond the x-y plane occupied by the initial slice.
+const FIRST_MIRRORED_DIM: usize = 2;
+
+/// Represents the Conway Cube introduced in AOC 2020 Day 17, generalised to any number of
+/// dimensions from 1 to 6 (3 for Part 1 and 4 for Part 2). By default, cubes follow the B3/S23 rule
+/// over the Moore neighbourhood, but any Life-like rule and neighbourhood can be used instead. Only
+/// the locations of active cubes are stored, with all other cubes being inactive.
+///
+/// As the initial slice lies in the x-y plane, the Conway Cube stays symmetric under reflection in
+/// each of its other coordinates (z -> -z, w -> -w and so on). With mirror symmetry enabled, only
+/// the active cubes with non-negative coordinates beyond x and y are stored and simulated, each
+/// standing in for all of its mirror images.
+#[derive(Clone)]
+pub struct ConwayCube<const D: usize> {
+    active_cubes: HashSet<Point<D>>,
+    steps: u64,
+    rule: LifeRule,
+    neighbourhood: Neighbourhood,
+    mirrored: bool,
+}
+
+/// Three-dimensional Conway Cube, as used in AOC 2020 Day 17 Part 1.
+pub type ConwayCube3D = ConwayCube<3>;
+/// Four-dimensional Conway Cube, as used in AOC 2020 Day 17 Part 2.
+pub type ConwayCube4D = ConwayCube<4>;
+
+impl<const D: usize> ConwayCube<D> {
+    /// Creates a new Conway Cube from the raw input representing a section of a 2D plane. The 2D
+    /// area represented by the raw input is taken to occupy the X-Y plane (all other coordinates
+    /// 0), with the top-left-most location in the raw input located at (x: 0, y: 0). For a 1D
+    /// Conway Cube, the raw input must consist of a single row.
+    ///
+    /// Panics if the number of dimensions is not from 1 to 6, the raw input contains a character
+    /// other than '#' or '.', or the raw input has multiple rows for a 1D Conway Cube.
+    pub fn new(raw_input: &str) -> Self {
+        if !(1..=6).contains(&D) {
+            panic!("ConwayCube - unsupported number of dimensions: {}", D);
+        }
+        let mut active_cubes: HashSet<Point<D>> = HashSet::new();
+        for (y, line) in raw_input.lines().map(|line| line.trim()).enumerate() {
+            if D == 1 && y > 0 {
+                panic!("ConwayCube - multiple rows in raw input for 1D Conway Cube!");
+            }
+            for (x, c) in line.chars().enumerate() {
+                let mut loc = Point::origin();
+                loc[0] = x as i64;
+                if D > 1 {
+                    loc[1] = y as i64;
+                }
+                match c {
+                    '.' => (),
+                    '#' => {
+                        active_cubes.insert(loc);
+                    },
+                    _ => panic!("ConwayCube - bad character in raw input!"),
+                }
+            }
+        }
+        Self {
+            active_cubes,
+            steps: 0,
+            rule: LifeRule::conway(),
+            neighbourhood: Neighbourhood::moore(),
+            mirrored: false,
+        }
+    }
+
+    /// Gets the Conway Cube with its cubes following the given rule in subsequent steps. Birth with
+    /// zero active neighbours (B0) is not supported, as it would activate infinitely many cubes.
+    ///
+    /// Panics if the rule includes birth with zero active neighbours.
+    pub fn with_rule(self, rule: LifeRule) -> Self {
+        assert!(
+            !rule.get_birth().contains(&0),
+            "ConwayCube - birth with zero active neighbours (B0) is not supported: {}", rule,
+        );
+        Self {
+            active_cubes: self.active_cubes,
+            steps: self.steps,
+            rule,
+            neighbourhood: self.neighbourhood,
+            mirrored: self.mirrored,
+        }
+    }
+
+    /// Gets the Conway Cube with the active neighbours of each cube counted over the given
+    /// neighbourhood in subsequent steps.
+    pub fn with_neighbourhood(self, neighbourhood: Neighbourhood) -> Self {
+        Self {
+            active_cubes: self.active_cubes,
+            steps: self.steps,
+            rule: self.rule,
+            neighbourhood,
+            mirrored: self.mirrored,
+        }
+    }
+
+    /// Gets the Conway Cube with its mirror symmetry beyond the x-y plane exploited in subsequent
+    /// steps, so that only the non-negative half of each of those dimensions is simulated. The
+    /// results are identical to those of the full simulation, while each step is conducted roughly
+    /// 2^(D-2) times faster.
+    pub fn with_mirror_symmetry(self) -> Self {
+        let active_cubes = self
+            .active_cubes
+            .iter()
+            .map(Self::get_canonical_loc)
+            .collect::<HashSet<Point<D>>>();
+        Self {
+            active_cubes,
+            steps: self.steps,
+            rule: self.rule,
+            neighbourhood: self.neighbourhood,
+            mirrored: true,
+        }
+    }
+
+    /// Checks if the mirror symmetry of the Conway Cube is exploited when conducting steps.
+    pub fn is_mirror_symmetric(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Gets the rule followed by the cubes.
+    pub fn get_rule(&self) -> &LifeRule {
+        &self.rule
+    }
+
+    /// Gets the neighbourhood over which the active neighbours of each cube are counted.
+    pub fn get_neighbourhood(&self) -> &Neighbourhood {
+        &self.neighbourhood
+    }
+
+    /// Gets the number of steps conducted so far.
+    pub fn get_steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Counts the number of cubes in the active state.
+    pub fn count_active_cubes(&self) -> u64 {
+        if self.mirrored {
+            return self.active_cubes.iter().map(Self::get_mirror_count).sum();
+        }
+        self.active_cubes.len() as u64
+    }
+
+    /// Gets the smallest bounding box containing every active cube, or None if no cubes are
+    /// active.
+    pub fn get_active_bounds(&self) -> Option<Aabb<D>> {
+        let bounds = Aabb::from_points(self.active_cubes.iter().copied())?;
+        if self.mirrored {
+            // Extend the bounds to cover the mirror images of the stored cubes
+            let mut mirror_corner = bounds.get_max();
+            for dim in FIRST_MIRRORED_DIM..D {
+                mirror_corner[dim] = -mirror_corner[dim];
+            }
+            return Some(bounds.include(&mirror_corner));
+        }
+        Some(bounds)
+    }
+
+    /// Conducts a single step for the Conway Cube, following its rule (by default, the rules set
+    /// out in AOC 2020 Day 17).
+    pub fn conduct_step(&mut self) {
+        self.steps += 1;
+        // Count the active neighbours of every cube near an active cube in a single pass, by having
+        // each active cube add one to the count of each location in its neighbourhood
+        let neighbourhood_size = self.neighbourhood.iter(&Point::<D>::origin()).count();
+        let capacity = self.active_cubes.len() * neighbourhood_size;
+        let mut neighbour_counts: HashMap<Point<D>, u64> = HashMap::with_capacity(capacity);
+        for loc in self.active_cubes.iter() {
+            // With mirror symmetry, each stored cube adds its number of mirror images to the count
+            // of the stored mirror image of each neighbour. This counts the active neighbours of
+            // each location once for each of its own mirror images, which is divided out below.
+            let weight = if self.mirrored { Self::get_mirror_count(loc) } else { 1 };
+            for neighbour in self.neighbourhood.iter(loc) {
+                let neighbour = if self.mirrored {
+                    Self::get_canonical_loc(&neighbour)
+                } else {
+                    neighbour
+                };
+                *neighbour_counts.entry(neighbour).or_insert(0) += weight;
+            }
+        }
+        let mut next_active_cubes: HashSet<Point<D>> = neighbour_counts
+            .iter()
+            .filter(|(loc, count)| {
+                let count = if self.mirrored {
+                    **count / Self::get_mirror_count(loc)
+                } else {
+                    **count
+                };
+                self.rule.next_state(self.active_cubes.contains(loc), count)
+            })
+            .map(|(loc, _count)| *loc)
+            .collect();
+        // Active cubes without any active neighbours are missed by the counts
+        for loc in self.active_cubes.iter() {
+            if !neighbour_counts.contains_key(loc) && self.rule.next_state(true, 0) {
+                next_active_cubes.insert(*loc);
+            }
+        }
+        self.active_cubes = next_active_cubes;
+    }
+
+    /// Gets the character representing the state of the cube at the given location ('#' if active,
+    /// '.' otherwise).
+    fn get_cube_char(&self, loc: &Point<D>) -> char {
+        let loc = if self.mirrored { Self::get_canonical_loc(loc) } else { *loc };
+        if self.active_cubes.contains(&loc) {
+            return '#';
+        }
+        '.'
+    }
+
+    /// Gets the location of the mirror image of the given location that is stored when mirror
+    /// symmetry is exploited, having non-negative coordinates beyond x and y.
+    fn get_canonical_loc(loc: &Point<D>) -> Point<D> {
+        let mut canonical_loc = *loc;
+        for dim in FIRST_MIRRORED_DIM..D {
+            canonical_loc[dim] = canonical_loc[dim].abs();
+        }
+        canonical_loc
+    }
+
+    /// Gets the number of distinct mirror images of the given location (including itself), being
+    /// 2 raised to the number of its non-zero coordinates beyond x and y.
+    fn get_mirror_count(loc: &Point<D>) -> u64 {
+        let non_zero = (FIRST_MIRRORED_DIM..D).filter(|dim| loc[*dim] != 0).count();
+        1 << non_zero
+    }
+}
+
+impl<const D: usize> CellularAutomaton for ConwayCube<D> {
+    /// Locations of the stored active cubes, in ascending order.
+    type State = Vec<Point<D>>;
+
+    fn conduct_step(&mut self) {
+        ConwayCube::conduct_step(self);
+    }
+
+    fn get_steps(&self) -> u64 {
+        self.steps
+    }
+
+    fn get_population(&self) -> u64 {
+        self.count_active_cubes()
+    }
+
+    fn get_state(&self) -> Self::State {
+        let mut active_cubes = self.active_cubes.iter().copied().collect::<Vec<Point<D>>>();
+        active_cubes.sort_unstable();
+        active_cubes
+    }
+}
+
+impl<const D: usize> fmt::Display for ConwayCube<D> {
+    /// Formats the region of the cube containing active cubes as a series of x-y slices, in the
+    /// style used by the AOC 2020 Day 17 puzzle description (active as '#', inactive as '.'). Each
+    /// slice is labelled with its remaining coordinates, ordered with the last coordinate changing
+    /// slowest (e.g. "z=-1, w=0"). A 1D Conway Cube is formatted as a single row.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bounds = match self.get_active_bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let (min, max) = (bounds.get_min(), bounds.get_max());
+        // Find the corner (minimum x and y) of each slice
+        let mut slice_corners = bounds
+            .iter()
+            .filter(|loc| (0..D.min(2)).all(|dim| loc[dim] == min[dim]))
+            .collect::<Vec<Point<D>>>();
+        slice_corners.sort_by_key(|loc| {
+            loc.get_coords().iter().rev().copied().collect::<Vec<i64>>()
+        });
+        let mut slices: Vec<String> = vec![];
+        for corner in slice_corners {
+            let mut lines: Vec<String> = vec![];
+            if D > 2 {
+                let labels = (2..D)
+                    .map(|dim| format!("{}={}", COORD_NAMES[dim], corner[dim]))
+                    .collect::<Vec<String>>();
+                lines.push(labels.join(", "));
+            }
+            let (min_y, max_y) = if D > 1 { (min[1], max[1]) } else { (0, 0) };
+            for y in min_y..=max_y {
+                let mut loc = corner;
+                if D > 1 {
+                    loc[1] = y;
+                }
+                let mut line = String::new();
+                for x in min[0]..=max[0] {
+                    loc[0] = x;
+                    line.push(self.get_cube_char(&loc));
+                }
+                lines.push(line);
+            }
+            slices.push(lines.join("\n"));
+        }
+        write!(f, "{}", slices.join("\n\n"))
+    }
+}
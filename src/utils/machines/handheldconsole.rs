@@ -1,7 +1,5 @@
 use std::collections::HashMap;
 
-use regex::Regex;
-
 /// Represents the different operations that can be executed by the handheld console specified in
 /// AoC 2020 Day 8.
 #[derive(Copy, Clone, Hash, PartialEq, Eq)]
@@ -16,9 +14,26 @@ impl HandheldConsoleOp {
     /// unchanged.
     pub fn toggle(&self) -> Self {
         match self {
-            HandheldConsoleOp::Acc{arg} => return HandheldConsoleOp::Acc{arg: *arg},
-            HandheldConsoleOp::Jmp{arg} => return HandheldConsoleOp::Nop{arg: *arg},
-            HandheldConsoleOp::Nop{arg} => return HandheldConsoleOp::Jmp{arg: *arg},
+            HandheldConsoleOp::Acc{arg} => HandheldConsoleOp::Acc{arg: *arg},
+            HandheldConsoleOp::Jmp{arg} => HandheldConsoleOp::Nop{arg: *arg},
+            HandheldConsoleOp::Nop{arg} => HandheldConsoleOp::Jmp{arg: *arg},
+        }
+    }
+
+    /// Parses a single instruction from the given line, consisting of the operation followed by a
+    /// signed argument (e.g. "jmp -4"). Returns None if the line is not a valid instruction.
+    pub fn from_string(line: &str) -> Option<HandheldConsoleOp> {
+        let mut parts = line.split_whitespace();
+        let (op, raw_arg) = (parts.next()?, parts.next()?);
+        if parts.next().is_some() || !(raw_arg.starts_with('+') || raw_arg.starts_with('-')) {
+            return None;
+        }
+        let arg = raw_arg.parse::<isize>().ok()?;
+        match op {
+            "acc" => Some(HandheldConsoleOp::Acc{arg}),
+            "jmp" => Some(HandheldConsoleOp::Jmp{arg}),
+            "nop" => Some(HandheldConsoleOp::Nop{arg}),
+            _ => None,
         }
     }
 
@@ -26,31 +41,14 @@ impl HandheldConsoleOp {
     /// line.
     pub fn parse_raw_code(input: &str) -> Option<Vec<HandheldConsoleOp>> {
         let mut instructions: Vec<HandheldConsoleOp> = vec![];
-        let acc_regex = Regex::new(r"acc ([-|+]\d+)").unwrap();
-        let jmp_regex = Regex::new(r"jmp ([-|+]\d+)").unwrap();
-        let nop_regex = Regex::new(r"nop ([-|+]\d+)").unwrap();
         for line in input.lines() {
             let line = line.trim();
             if line.is_empty() {
                 continue;
             }
-            if acc_regex.is_match(line) {
-                let captures = acc_regex.captures(line).unwrap();
-                let arg = captures[1].parse::<isize>().unwrap();
-                instructions.push(HandheldConsoleOp::Acc{arg: arg});
-            } else if jmp_regex.is_match(line) {
-                let captures = jmp_regex.captures(line).unwrap();
-                let arg = captures[1].parse::<isize>().unwrap();
-                instructions.push(HandheldConsoleOp::Jmp{arg: arg});
-            } else if nop_regex.is_match(line) {
-                let captures = nop_regex.captures(line).unwrap();
-                let arg = captures[1].parse::<isize>().unwrap();
-                instructions.push(HandheldConsoleOp::Nop{arg: arg});
-            } else {
-                return None;
-            }
+            instructions.push(HandheldConsoleOp::from_string(line)?);
         }
-        return Some(instructions);
+        Some(instructions)
     }
 }
 
@@ -66,9 +64,9 @@ pub struct HandheldConsole {
 }
 
 impl HandheldConsole {
-    pub fn new(instructions: &Vec<HandheldConsoleOp>) -> Self {
+    pub fn new(instructions: &[HandheldConsoleOp]) -> Self {
         Self {
-            instructions: instructions.clone(),
+            instructions: instructions.to_vec(),
             toggle_i: 0,
             accumulator: 0,
             halted: false,
@@ -108,24 +106,24 @@ impl HandheldConsole {
 
     /// Checks if the HandheldConsole has been halted.
     pub fn is_halted(&self) -> bool {
-        return self.halted;
+        self.halted
     }
 
     /// Checks if the HandheldConsole has any more Jmp or Nop instructions that have not yet been
     /// toggled.
     pub fn check_toggles_exhausted(&self) -> bool {
-        return self.toggle_i >= self.instructions.len();
+        self.toggle_i >= self.instructions.len()
     }
 
     /// Gets the value of the HandheldConsole accumulator.
     pub fn get_accumulator(&self) -> isize {
-        return self.accumulator;
+        self.accumulator
     }
 
     /// Executes the next instruction, halting if the instruction has already been executed (if
     /// option specified).
     fn execute_single_instruction(&mut self, pause_on_history: bool) {
-        if self.halted == true {
+        if self.halted {
             return;
         }
         // Check if program counter is within instruction space
@@ -164,7 +162,7 @@ impl HandheldConsole {
             if !pause_on_history && step >= steps {
                 return;
             }
-            if self.halted == true {
+            if self.halted {
                 return;
             }
             step += 1;
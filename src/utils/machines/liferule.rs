@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Represents the rule of a Life-like cellular automaton, given by the numbers of active
+/// neighbours for which an inactive cell becomes active (birth) and an active cell stays active
+/// (survival). All other cells become or stay inactive.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct LifeRule {
+    birth: Vec<u64>,
+    survival: Vec<u64>,
+}
+
+impl LifeRule {
+    /// Creates a new rule with the given birth and survival neighbour counts.
+    pub fn new(birth: &[u64], survival: &[u64]) -> Self {
+        let mut birth = birth.to_vec();
+        birth.sort_unstable();
+        birth.dedup();
+        let mut survival = survival.to_vec();
+        survival.sort_unstable();
+        survival.dedup();
+        Self {
+            birth,
+            survival,
+        }
+    }
+
+    /// Gets the rule used by Conway's Game of Life and the AOC 2020 Day 17 Conway Cubes (B3/S23).
+    pub fn conway() -> Self {
+        LifeRule::new(&[3], &[2, 3])
+    }
+
+    /// Gets the neighbour counts for which an inactive cell becomes active, in ascending order.
+    pub fn get_birth(&self) -> &Vec<u64> {
+        &self.birth
+    }
+
+    /// Gets the neighbour counts for which an active cell stays active, in ascending order.
+    pub fn get_survival(&self) -> &Vec<u64> {
+        &self.survival
+    }
+
+    /// Determines the next state of a cell (true for active) from its current state and number of
+    /// active neighbours.
+    pub fn next_state(&self, active: bool, active_neighbours: u64) -> bool {
+        if active {
+            return self.survival.contains(&active_neighbours);
+        }
+        self.birth.contains(&active_neighbours)
+    }
+}
+
+impl Default for LifeRule {
+    fn default() -> Self {
+        LifeRule::conway()
+    }
+}
+
+impl fmt::Display for LifeRule {
+    /// Formats the rule in B/S notation (e.g. "B3/S23"), with the counts separated by commas if
+    /// any count is above 8 (e.g. "B3,12/S2,3").
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let separator = if self.birth.iter().chain(self.survival.iter()).any(|x| *x > 8) {
+            ","
+        } else {
+            ""
+        };
+        let join = |counts: &Vec<u64>| {
+            counts.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(separator)
+        };
+        write!(f, "B{}/S{}", join(&self.birth), join(&self.survival))
+    }
+}
+
+/// Error returned when a Life-like rule cannot be parsed from a string.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct ParseRuleError {
+    text: String,
+    message: String,
+}
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid rule: {} ---- \"{}\"", self.message, self.text)
+    }
+}
+
+impl Error for ParseRuleError {}
+
+impl FromStr for LifeRule {
+    type Err = ParseRuleError;
+
+    /// Parses a rule from B/S notation (e.g. "B3/S23"), ignoring case and surrounding whitespace.
+    /// The birth part must come before the survival part, and either may have no counts. Each
+    /// digit from 0 to 8 is a separate count, unless the counts are separated by commas (e.g.
+    /// "B3/S2,3,10"), in which case any count may be given.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |message: &str| ParseRuleError {
+            text: s.to_string(),
+            message: message.to_string(),
+        };
+        let parts = s.trim().split('/').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(error("expected birth and survival parts separated by '/'"));
+        }
+        let mut counts_by_part: Vec<Vec<u64>> = vec![];
+        for (part, expected_kind) in parts.iter().zip(['B', 'S'].iter()) {
+            let part = part.trim();
+            let (kind, counts) = match part.chars().next() {
+                Some(c) => (c.to_ascii_uppercase(), &part[c.len_utf8()..]),
+                None => return Err(error("empty rule part")),
+            };
+            if kind != *expected_kind {
+                return Err(error("expected birth part ('B') followed by survival part ('S')"));
+            }
+            let counts = if counts.contains(',') {
+                counts
+                    .split(',')
+                    .map(|x| x.trim().parse::<u64>().ok())
+                    .collect::<Option<Vec<u64>>>()
+            } else {
+                // Digits are read in base 9, so only counts from 0 to 8 are accepted
+                counts
+                    .chars()
+                    .map(|c| c.to_digit(9).map(u64::from))
+                    .collect::<Option<Vec<u64>>>()
+            };
+            let counts = match counts {
+                Some(counts) => counts,
+                None => return Err(error("bad neighbour count")),
+            };
+            counts_by_part.push(counts);
+        }
+        Ok(LifeRule::new(&counts_by_part[0], &counts_by_part[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_round_trip() {
+        for text in ["B3/S23", "B36/S23", "B/S012345678", "B3,9/S2,3"].iter() {
+            let rule = text.parse::<LifeRule>().unwrap();
+            assert_eq!(*text, rule.to_string());
+        }
+        assert_eq!("B36/S23", LifeRule::new(&[6, 3, 3], &[3, 2]).to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(LifeRule::conway(), "B3/S23".parse().unwrap());
+        assert_eq!(LifeRule::conway(), " b3 / s2,3 ".parse().unwrap());
+        let rule = "B3/S2, 3, 10".parse::<LifeRule>().unwrap();
+        assert_eq!(&vec![3], rule.get_birth());
+        assert_eq!(&vec![2, 3, 10], rule.get_survival());
+    }
+
+    #[test]
+    fn test_parse_rejected() {
+        for text in ["S23/B3", "B9/S23", "", "B3", "B3/S23/S4", "B3/X23", "B3/S2,x"].iter() {
+            assert!(text.parse::<LifeRule>().is_err(), "\"{}\" should be rejected", text);
+        }
+    }
+}
@@ -1,8 +1,16 @@
-mod conwaycube3d;
-mod conwaycube4d;
+mod automaton;
+mod automatonhistory;
+mod conwaycube;
 mod handheldconsole;
+mod liferule;
 
-pub use self::conwaycube3d::ConwayCube3D;
-pub use self::conwaycube4d::ConwayCube4D;
+pub use self::automaton::run_until_repeat;
+pub use self::automaton::CellularAutomaton;
+pub use self::automatonhistory::AutomatonHistory;
+pub use self::conwaycube::ConwayCube;
+pub use self::conwaycube::ConwayCube3D;
+pub use self::conwaycube::ConwayCube4D;
 pub use self::handheldconsole::HandheldConsole;
 pub use self::handheldconsole::HandheldConsoleOp;
+pub use self::liferule::LifeRule;
+pub use self::liferule::ParseRuleError;
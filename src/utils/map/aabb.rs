@@ -0,0 +1,217 @@
+use super::Point;
+
+/// Represents an axis-aligned bounding box in N-dimensional space, given by its minimum and maximum
+/// corners. Both corners are included in the box, so a box always contains at least one point.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Aabb<const N: usize> {
+    min: Point<N>,
+    max: Point<N>,
+}
+
+impl<const N: usize> Aabb<N> {
+    /// Creates the smallest bounding box containing both of the given corner points.
+    pub fn new(corner_1: &Point<N>, corner_2: &Point<N>) -> Self {
+        let mut min = *corner_1;
+        let mut max = *corner_1;
+        for dim in 0..N {
+            min[dim] = corner_1[dim].min(corner_2[dim]);
+            max[dim] = corner_1[dim].max(corner_2[dim]);
+        }
+        Self {
+            min,
+            max,
+        }
+    }
+
+    /// Creates the bounding box containing only the given point.
+    pub fn from_point(point: &Point<N>) -> Self {
+        Aabb::new(point, point)
+    }
+
+    /// Creates the smallest bounding box containing all of the given points, or None if there are
+    /// no points.
+    pub fn from_points<I: IntoIterator<Item = Point<N>>>(points: I) -> Option<Self> {
+        let mut output: Option<Aabb<N>> = None;
+        for point in points {
+            output = match output {
+                None => Some(Aabb::from_point(&point)),
+                Some(aabb) => Some(aabb.include(&point)),
+            };
+        }
+        output
+    }
+
+    /// Gets the minimum corner of the bounding box.
+    pub fn get_min(&self) -> Point<N> {
+        self.min
+    }
+
+    /// Gets the maximum corner of the bounding box.
+    pub fn get_max(&self) -> Point<N> {
+        self.max
+    }
+
+    /// Gets the number of points spanned by the bounding box in each dimension.
+    pub fn get_size(&self) -> [u64; N] {
+        let mut size = [0; N];
+        for (dim, length) in size.iter_mut().enumerate() {
+            *length = (self.max[dim] - self.min[dim]) as u64 + 1;
+        }
+        size
+    }
+
+    /// Calculates the volume of the bounding box, being the number of points it contains.
+    pub fn volume(&self) -> u64 {
+        self.get_size().iter().product()
+    }
+
+    /// Checks if the given point lies within the bounding box.
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|dim| self.min[dim] <= point[dim] && point[dim] <= self.max[dim])
+    }
+
+    /// Gets the smallest bounding box containing both the current bounding box and the other.
+    pub fn union(&self, other: &Aabb<N>) -> Aabb<N> {
+        self.include(&other.min).include(&other.max)
+    }
+
+    /// Gets the smallest bounding box containing both the current bounding box and the point.
+    pub fn include(&self, point: &Point<N>) -> Aabb<N> {
+        let mut output = *self;
+        for dim in 0..N {
+            output.min[dim] = self.min[dim].min(point[dim]);
+            output.max[dim] = self.max[dim].max(point[dim]);
+        }
+        output
+    }
+
+    /// Gets the bounding box grown by the given margin on every side.
+    pub fn expand(&self, margin: u64) -> Aabb<N> {
+        let margin = Point::from_coords([margin as i64; N]);
+        Aabb {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    /// Gets an iterator over every point within the bounding box, without allocating. Points are
+    /// generated in lexicographic order of their coordinates.
+    pub fn iter(&self) -> AabbIter<N> {
+        AabbIter {
+            aabb: *self,
+            next_point: Some(self.min),
+        }
+    }
+}
+
+impl<const N: usize> IntoIterator for &Aabb<N> {
+    type Item = Point<N>;
+    type IntoIter = AabbIter<N>;
+
+    fn into_iter(self) -> AabbIter<N> {
+        self.iter()
+    }
+}
+
+/// Iterator over every point within an axis-aligned bounding box.
+pub struct AabbIter<const N: usize> {
+    aabb: Aabb<N>,
+    next_point: Option<Point<N>>,
+}
+
+impl<const N: usize> Iterator for AabbIter<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Point<N>> {
+        let point = self.next_point?;
+        // Advance to the next point, with the last coordinate changing fastest
+        let mut next_point = point;
+        self.next_point = None;
+        for dim in (0..N).rev() {
+            if next_point[dim] < self.aabb.max[dim] {
+                next_point[dim] += 1;
+                self.next_point = Some(next_point);
+                break;
+            }
+            next_point[dim] = self.aabb.min[dim];
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::map::Point2D;
+    use crate::utils::map::Point3D;
+    use crate::utils::map::Point4D;
+
+    #[test]
+    fn test_from_points() {
+        let points = vec![Point2D::new(2, -1), Point2D::new(-3, 4), Point2D::new(0, 0)];
+        let aabb = Aabb::from_points(points).unwrap();
+        assert_eq!(Point2D::new(-3, -1), aabb.get_min());
+        assert_eq!(Point2D::new(2, 4), aabb.get_max());
+        assert_eq!([6, 6], aabb.get_size());
+        assert_eq!(None, Aabb::<2>::from_points(vec![]));
+    }
+
+    #[test]
+    fn test_contains() {
+        let aabb = Aabb::new(&Point3D::new(1, 5, -2), &Point3D::new(-1, 2, 0));
+        assert!(aabb.contains(&Point3D::new(-1, 2, -2)));
+        assert!(aabb.contains(&Point3D::new(1, 5, 0)));
+        assert!(aabb.contains(&Point3D::new(0, 3, -1)));
+        assert!(!aabb.contains(&Point3D::new(2, 3, -1)));
+        assert!(!aabb.contains(&Point3D::new(0, 1, -1)));
+        assert!(!aabb.contains(&Point3D::new(0, 3, 1)));
+    }
+
+    #[test]
+    fn test_volume() {
+        let aabb = Aabb::new(&Point4D::new(0, 0, 0, 0), &Point4D::new(1, 2, 3, 4));
+        assert_eq!(120, aabb.volume());
+        assert_eq!(1, Aabb::from_point(&Point2D::new(7, -7)).volume());
+    }
+
+    #[test]
+    fn test_union() {
+        let aabb_1 = Aabb::new(&Point2D::new(0, 0), &Point2D::new(2, 2));
+        let aabb_2 = Aabb::new(&Point2D::new(5, -3), &Point2D::new(6, 1));
+        let expected = Aabb::new(&Point2D::new(0, -3), &Point2D::new(6, 2));
+        assert_eq!(expected, aabb_1.union(&aabb_2));
+        assert_eq!(expected, aabb_2.union(&aabb_1));
+        assert_eq!(aabb_1, aabb_1.union(&Aabb::from_point(&Point2D::new(1, 1))));
+    }
+
+    #[test]
+    fn test_expand() {
+        let aabb = Aabb::from_point(&Point2D::new(3, -2)).expand(2);
+        assert_eq!(Point2D::new(1, -4), aabb.get_min());
+        assert_eq!(Point2D::new(5, 0), aabb.get_max());
+        assert_eq!(25, aabb.volume());
+        assert_eq!(aabb, aabb.expand(0));
+    }
+
+    #[test]
+    fn test_iter() {
+        let aabb = Aabb::new(&Point2D::new(0, 1), &Point2D::new(1, 3));
+        let expected = vec![
+            Point2D::new(0, 1),
+            Point2D::new(0, 2),
+            Point2D::new(0, 3),
+            Point2D::new(1, 1),
+            Point2D::new(1, 2),
+            Point2D::new(1, 3),
+        ];
+        assert_eq!(expected, aabb.iter().collect::<Vec<Point<2>>>());
+        assert_eq!(aabb.volume() as usize, (&aabb).into_iter().count());
+        assert!(aabb.iter().all(|point| aabb.contains(&point)));
+    }
+
+    #[test]
+    fn test_iter_single_point() {
+        let aabb = Aabb::from_point(&Point3D::new(4, -1, 2));
+        assert_eq!(vec![Point3D::new(4, -1, 2)], aabb.iter().collect::<Vec<Point<3>>>());
+    }
+}
@@ -1,5 +1,9 @@
+use super::Direction;
+use super::Point2D;
+use super::RotationError;
+
 /// Represents one of the four cardinal directions.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub enum CardinalDirection {
     North,
     East,
@@ -8,45 +12,118 @@ pub enum CardinalDirection {
 }
 
 impl CardinalDirection {
+    /// Parses a cardinal direction from a single character, accepting the same characters as
+    /// `Direction::from_char`.
+    pub fn from_char(c: char) -> Option<CardinalDirection> {
+        CardinalDirection::from_direction(Direction::from_char(c)?)
+    }
+
+    /// Converts the given eight-way direction into a cardinal direction, or None if the direction
+    /// is diagonal.
+    pub fn from_direction(direction: Direction) -> Option<CardinalDirection> {
+        match direction {
+            Direction::North => Some(CardinalDirection::North),
+            Direction::East => Some(CardinalDirection::East),
+            Direction::South => Some(CardinalDirection::South),
+            Direction::West => Some(CardinalDirection::West),
+            _ => None,
+        }
+    }
+
+    /// Converts the cardinal direction into the equivalent eight-way direction.
+    pub fn to_direction(&self) -> Direction {
+        match self {
+            CardinalDirection::North => Direction::North,
+            CardinalDirection::East => Direction::East,
+            CardinalDirection::South => Direction::South,
+            CardinalDirection::West => Direction::West,
+        }
+    }
+
+    /// Gets the unit delta vector for the direction.
+    pub fn get_delta(&self) -> Point2D {
+        self.to_direction().get_delta()
+    }
+
+    /// Gets the direction opposite to the current direction.
+    pub fn reverse(&self) -> CardinalDirection {
+        CardinalDirection::from_direction(self.to_direction().reverse()).unwrap()
+    }
+
     /// Determines the resulting direction by conducting single 90 rotation to left (CCW).
     pub fn rotate_left_90_degrees(&self) -> CardinalDirection {
         match self {
-            CardinalDirection::North => return CardinalDirection::West,
-            CardinalDirection::East => return CardinalDirection::North,
-            CardinalDirection::South => return CardinalDirection::East,
-            CardinalDirection::West => return CardinalDirection::South,
+            CardinalDirection::North => CardinalDirection::West,
+            CardinalDirection::East => CardinalDirection::North,
+            CardinalDirection::South => CardinalDirection::East,
+            CardinalDirection::West => CardinalDirection::South,
         }
     }
 
     /// Determines the resulting direction by conducting single 90 rotation to right (CW).
     pub fn rotate_right_90_degrees(&self) -> CardinalDirection {
         match self {
-            CardinalDirection::North => return CardinalDirection::East,
-            CardinalDirection::East => return CardinalDirection::South,
-            CardinalDirection::South => return CardinalDirection::West,
-            CardinalDirection::West => return CardinalDirection::North,
+            CardinalDirection::North => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::North,
         }
     }
 
-    /// Determines the resulting direction by conducting N rotations to the right (CW), where N is
-    /// number of 90 degrees in specified value.
-    pub fn rotate_right(&self, degrees: u64) -> CardinalDirection {
-        let rotations = degrees / 90;
-        let mut direction = *self;
-        for _ in 0..rotations {
-            direction = direction.rotate_right_90_degrees();
+    /// Determines the resulting direction by rotating to the right (CW) by the specified number of
+    /// degrees. Returns an error if the angle is not a multiple of 90 degrees.
+    pub fn rotate_right(&self, degrees: i64) -> Result<CardinalDirection, RotationError> {
+        if degrees % 90 != 0 {
+            return Err(RotationError::new(degrees, 90));
         }
-        return direction;
+        let direction = self.to_direction().rotate_clockwise(degrees)?;
+        Ok(CardinalDirection::from_direction(direction).unwrap())
     }
 
-    /// Determines the resulting direction by conducting N rotations to the left (CCW), where N is
-    /// number of 90 degrees in specified value.
-    pub fn rotate_left(&self, degrees: u64) -> CardinalDirection {
-        let rotations = degrees / 90;
-        let mut direction = *self;
-        for _ in 0..rotations {
-            direction = direction.rotate_left_90_degrees();
-        }
-        return direction;
+    /// Determines the resulting direction by rotating to the left (CCW) by the specified number of
+    /// degrees. Returns an error if the angle is not a multiple of 90 degrees.
+    pub fn rotate_left(&self, degrees: i64) -> Result<CardinalDirection, RotationError> {
+        self.rotate_right(-degrees)
+    }
+}
+
+impl From<CardinalDirection> for Direction {
+    fn from(direction: CardinalDirection) -> Direction {
+        direction.to_direction()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Some(CardinalDirection::North), CardinalDirection::from_char('^'));
+        assert_eq!(Some(CardinalDirection::East), CardinalDirection::from_char('e'));
+        assert_eq!(Some(CardinalDirection::South), CardinalDirection::from_char('D'));
+        assert_eq!(Some(CardinalDirection::West), CardinalDirection::from_char('<'));
+        assert_eq!(None, CardinalDirection::from_char('x'));
+        assert_eq!(None, CardinalDirection::from_direction(Direction::NorthEast));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let direction = CardinalDirection::North;
+        assert_eq!(Ok(CardinalDirection::West), direction.rotate_right(270));
+        assert_eq!(Ok(CardinalDirection::West), direction.rotate_left(90));
+        assert_eq!(Ok(CardinalDirection::South), direction.rotate_left(-180));
+        assert_eq!(CardinalDirection::East, direction.rotate_right_90_degrees());
+        assert_eq!(CardinalDirection::South, direction.reverse());
+        assert_eq!(Direction::West, Direction::from(CardinalDirection::West));
+    }
+
+    #[test]
+    fn test_rotate_rejected() {
+        // Multiples of 45 degrees that are not multiples of 90 degrees would be diagonal
+        let error = CardinalDirection::North.rotate_right(45).unwrap_err();
+        assert_eq!("rotation of 45 degrees is not a multiple of 90 degrees", error.to_string());
+        let error = CardinalDirection::East.rotate_left(10).unwrap_err();
+        assert_eq!(-10, error.get_degrees());
     }
 }
@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::fmt;
+
+use super::Point2D;
+
+/// Represents one of the eight compass directions, being the four cardinal directions and the four
+/// intercardinal (diagonal) directions between them. Directions use screen coordinates, with North
+/// being towards negative y and East being towards positive x.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// All eight directions, in clockwise order starting from North.
+const DIRECTIONS: [Direction; 8] = [
+    Direction::North,
+    Direction::NorthEast,
+    Direction::East,
+    Direction::SouthEast,
+    Direction::South,
+    Direction::SouthWest,
+    Direction::West,
+    Direction::NorthWest,
+];
+
+impl Direction {
+    /// Gets all eight directions, in clockwise order starting from North.
+    pub fn get_all() -> [Direction; 8] {
+        DIRECTIONS
+    }
+
+    /// Gets the four cardinal directions, in clockwise order starting from North.
+    pub fn get_cardinals() -> [Direction; 4] {
+        [Direction::North, Direction::East, Direction::South, Direction::West]
+    }
+
+    /// Parses a direction from a single character. Accepts compass letters (N, E, S, W), relative
+    /// letters (U, R, D, L) and arrows (^, >, v, <), with letters in either case.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c.to_ascii_uppercase() {
+            'N' | 'U' | '^' => Some(Direction::North),
+            'E' | 'R' | '>' => Some(Direction::East),
+            'S' | 'D' | 'V' => Some(Direction::South),
+            'W' | 'L' | '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Checks if the direction is one of the four cardinal directions.
+    pub fn is_cardinal(&self) -> bool {
+        matches!(self, Direction::North | Direction::East | Direction::South | Direction::West)
+    }
+
+    /// Gets the unit delta vector for the direction, with diagonal directions changing both the x-
+    /// and y-coordinates by one.
+    pub fn get_delta(&self) -> Point2D {
+        match self {
+            Direction::North => Point2D::new(0, -1),
+            Direction::NorthEast => Point2D::new(1, -1),
+            Direction::East => Point2D::new(1, 0),
+            Direction::SouthEast => Point2D::new(1, 1),
+            Direction::South => Point2D::new(0, 1),
+            Direction::SouthWest => Point2D::new(-1, 1),
+            Direction::West => Point2D::new(-1, 0),
+            Direction::NorthWest => Point2D::new(-1, -1),
+        }
+    }
+
+    /// Gets the direction opposite to the current direction.
+    pub fn reverse(&self) -> Direction {
+        DIRECTIONS[(self.get_index() + 4) % 8]
+    }
+
+    /// Determines the resulting direction after rotating clockwise (right) by the given angle in
+    /// degrees, with negative angles rotating anticlockwise. Returns an error if the angle is not a
+    /// multiple of 45 degrees.
+    pub fn rotate_clockwise(&self, degrees: i64) -> Result<Direction, RotationError> {
+        if degrees % 45 != 0 {
+            return Err(RotationError::new(degrees, 45));
+        }
+        let index = (self.get_index() as i64 + degrees / 45).rem_euclid(8);
+        Ok(DIRECTIONS[index as usize])
+    }
+
+    /// Determines the resulting direction after rotating anticlockwise (left) by the given angle in
+    /// degrees, with negative angles rotating clockwise. Returns an error if the angle is not a
+    /// multiple of 45 degrees.
+    pub fn rotate_anticlockwise(&self, degrees: i64) -> Result<Direction, RotationError> {
+        self.rotate_clockwise(-degrees)
+    }
+
+    /// Gets the position of the direction in clockwise order starting from North.
+    fn get_index(&self) -> usize {
+        DIRECTIONS.iter().position(|dir| dir == self).unwrap()
+    }
+}
+
+/// Error returned when a direction is rotated by an angle it does not support.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct RotationError {
+    degrees: i64,
+    step: i64,
+}
+
+impl RotationError {
+    /// Creates a new error for a rotation by the given angle, where only multiples of the given
+    /// step (in degrees) are supported.
+    pub fn new(degrees: i64, step: i64) -> Self {
+        Self {
+            degrees,
+            step,
+        }
+    }
+
+    /// Gets the angle (in degrees) of the unsupported rotation.
+    pub fn get_degrees(&self) -> i64 {
+        self.degrees
+    }
+}
+
+impl fmt::Display for RotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "rotation of {} degrees is not a multiple of {} degrees",
+            self.degrees, self.step
+        )
+    }
+}
+
+impl Error for RotationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_char() {
+        for &c in ['N', 'n', 'U', 'u', '^'].iter() {
+            assert_eq!(Some(Direction::North), Direction::from_char(c));
+        }
+        for &c in ['E', 'R', 'r', '>'].iter() {
+            assert_eq!(Some(Direction::East), Direction::from_char(c));
+        }
+        for &c in ['S', 'D', 'v', 'V'].iter() {
+            assert_eq!(Some(Direction::South), Direction::from_char(c));
+        }
+        for &c in ['W', 'L', 'l', '<'].iter() {
+            assert_eq!(Some(Direction::West), Direction::from_char(c));
+        }
+        for &c in ['X', ' ', '1', 'F'].iter() {
+            assert_eq!(None, Direction::from_char(c));
+        }
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Ok(Direction::SouthEast), Direction::North.rotate_clockwise(135));
+        assert_eq!(Ok(Direction::NorthWest), Direction::North.rotate_anticlockwise(45));
+        assert_eq!(Ok(Direction::West), Direction::East.rotate_clockwise(-540));
+        assert_eq!(Ok(Direction::South), Direction::South.rotate_clockwise(720));
+        for direction in Direction::get_all().iter() {
+            assert_eq!(Ok(direction.reverse()), direction.rotate_clockwise(180));
+            assert_eq!(direction.get_delta(), -direction.reverse().get_delta());
+        }
+    }
+
+    #[test]
+    fn test_rotate_rejected() {
+        let error = Direction::North.rotate_clockwise(30).unwrap_err();
+        assert_eq!(30, error.get_degrees());
+        assert_eq!("rotation of 30 degrees is not a multiple of 45 degrees", error.to_string());
+        let error = Direction::East.rotate_anticlockwise(100).unwrap_err();
+        assert_eq!(-100, error.get_degrees());
+    }
+}
@@ -0,0 +1,373 @@
+use std::error::Error;
+use std::fmt;
+
+use super::GridTransform;
+use super::Point2D;
+
+use crate::error::ParseError;
+
+/// Represents a dense two-dimensional grid of cells, stored row by row in a flat vector. Locations
+/// within the grid are given as points, with (0, 0) being the top-left cell, x increasing to the
+/// right and y increasing downwards.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2D<T> {
+    /// Creates a new grid of the given width and height from the cells listed row by row. Returns
+    /// None if the number of cells does not match the size of the grid.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() != width * height {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Creates a new grid of the given width and height with every cell set to the given value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a grid from the given character map, with each non-empty line being one row of the
+    /// grid. Leading and trailing whitespace is removed from each line and empty lines are ignored.
+    /// Each character is converted to a cell using the given function. Returns an error locating
+    /// the offending character if it could not be converted, or if the rows differ in length.
+    pub fn from_str_with<E: fmt::Display>(input: &str, parse_cell: fn(char) -> Result<T, E>)
+            -> Result<Self, GridParseError>
+    {
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+        let mut cells: Vec<T> = vec![];
+        for (i, raw_line) in input.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // Columns are counted in characters from the start of the untrimmed line
+            let offset = raw_line[..raw_line.len() - raw_line.trim_start().len()].chars().count();
+            let mut row_width = 0;
+            for (j, c) in line.chars().enumerate() {
+                match parse_cell(c) {
+                    Ok(cell) => cells.push(cell),
+                    Err(e) => return Err(GridParseError {
+                        line: i,
+                        column: offset + j,
+                        text: c.to_string(),
+                        message: e.to_string(),
+                    }),
+                }
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => return Err(GridParseError {
+                    line: i,
+                    column: offset,
+                    text: line.to_string(),
+                    message: format!("row length {} differs from first row", row_width),
+                }),
+                _ => (),
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Gets the width of the grid (number of columns).
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the height of the grid (number of rows).
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks if the given location is within the bounds of the grid.
+    pub fn contains_point(&self, loc: &Point2D) -> bool {
+        loc.get_x() >= 0 && loc.get_y() >= 0
+            && (loc.get_x() as usize) < self.width && (loc.get_y() as usize) < self.height
+    }
+
+    /// Gets a reference to the cell at the given location, or None if the location is outside the
+    /// bounds of the grid.
+    pub fn get(&self, loc: &Point2D) -> Option<&T> {
+        let index = self.get_index(loc)?;
+        Some(&self.cells[index])
+    }
+
+    /// Gets a mutable reference to the cell at the given location, or None if the location is
+    /// outside the bounds of the grid.
+    pub fn get_mut(&mut self, loc: &Point2D) -> Option<&mut T> {
+        let index = self.get_index(loc)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Gets a reference to the cell at the given location, with the location wrapping around to
+    /// the opposite edge of the grid in both dimensions. Panics if the grid is empty.
+    pub fn get_wrapping(&self, loc: &Point2D) -> &T {
+        let x = loc.get_x().rem_euclid(self.width as i64) as usize;
+        let y = loc.get_y().rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Sets the value of the cell at the given location. Returns false (leaving the grid
+    /// unchanged) if the location is outside the bounds of the grid.
+    pub fn set(&mut self, loc: &Point2D, value: T) -> bool {
+        match self.get_mut(loc) {
+            Some(cell) => {
+                *cell = value;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Gets an iterator over the cells in the given row, from left to right. Panics if the row is
+    /// outside the bounds of the grid.
+    pub fn iter_row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "Grid2D - row {} out of bounds", y);
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// Gets an iterator over the cells in the given column, from top to bottom. Panics if the
+    /// column is outside the bounds of the grid.
+    pub fn iter_column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Grid2D - column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Gets an iterator over the rows of the grid, from top to bottom, with each row given as a
+    /// slice of its cells.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Gets an iterator over the cells of the grid, row by row, along with their locations.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        let width = self.width;
+        self
+            .cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point2D::new((i % width) as i64, (i / width) as i64), cell))
+    }
+
+    /// Gets an iterator over the locations of all cells in the grid, row by row.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point2D> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |i| Point2D::new((i % width) as i64, (i / width) as i64))
+    }
+
+    /// Counts the number of cells in the grid matching the given predicate.
+    pub fn count_matching<P: Fn(&T) -> bool>(&self, predicate: P) -> usize {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    /// Converts the grid to a character map, with each cell converted to a character using the
+    /// given function and each row on its own line.
+    pub fn to_string_with<F: Fn(&T) -> char>(&self, to_char: F) -> String {
+        self
+            .iter_rows()
+            .map(|row| row.iter().map(&to_char).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Gets a copy of the grid with the given transform applied. The width and height of the grid
+    /// are swapped if the transform includes a quarter turn.
+    pub fn transform(&self, transform: &GridTransform) -> Grid2D<T> where T: Clone {
+        let (width, height) = transform.get_transformed_size(self.width, self.height);
+        // Find the source of each cell in the transformed grid by applying the inverse transform
+        let location_map = transform.inverse().get_location_map(width, height);
+        let mut cells: Vec<T> = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                let (src_x, src_y) = location_map.map_location(x, y);
+                cells.push(self.cells[src_y * self.width + src_x].clone());
+            }
+        }
+        Grid2D {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Gets an iterator over the distinct orientations of the grid under the eight symmetries of a
+    /// square, along with the transform producing each. Orientations identical to one already
+    /// produced (as occurs for symmetric grids) are skipped. The first orientation is the grid
+    /// itself.
+    pub fn iter_orientations(&self) -> impl Iterator<Item = (GridTransform, Grid2D<T>)> + '_
+            where T: Clone + PartialEq
+    {
+        let mut seen: Vec<Grid2D<T>> = vec![];
+        GridTransform::get_all().to_vec().into_iter().filter_map(move |transform| {
+            let grid = self.transform(&transform);
+            if seen.contains(&grid) {
+                return None;
+            }
+            seen.push(grid.clone());
+            Some((transform, grid))
+        })
+    }
+
+    /// Determines the index into the flat cell vector for the given location, or None if the
+    /// location is outside the bounds of the grid.
+    fn get_index(&self, loc: &Point2D) -> Option<usize> {
+        if !self.contains_point(loc) {
+            return None;
+        }
+        Some(loc.get_y() as usize * self.width + loc.get_x() as usize)
+    }
+}
+
+impl<T: Copy + Into<char>> fmt::Display for Grid2D<T> {
+    /// Formats the grid as a character map, with each row on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(|cell| (*cell).into()))
+    }
+}
+
+/// Error returned when a grid cannot be parsed from a character map. The line index and column
+/// are zero-based, with the column counted in characters from the start of the untrimmed line.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct GridParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+impl GridParseError {
+    /// Gets the index (starting at 0) of the line containing the offending text.
+    pub fn get_line_index(&self) -> usize {
+        self.line
+    }
+
+    /// Gets the column (starting at 0) at which the offending text starts.
+    pub fn get_column_index(&self) -> usize {
+        self.column
+    }
+
+    /// Converts the error into a parse error for the puzzle input of the given day.
+    pub fn into_parse_error(self, day: u32) -> ParseError {
+        ParseError::new(day, self.line + 1, self.column + 1, &self.text, &self.message)
+    }
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} ---- \"{}\"",
+            self.line + 1, self.column + 1, self.message, self.text
+        )
+    }
+}
+
+impl Error for GridParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_tile(c: char) -> Result<char, String> {
+        match c {
+            '.' | '#' => Ok(c),
+            _ => Err(String::from("bad tile")),
+        }
+    }
+
+    fn sample_grid() -> Grid2D<char> {
+        Grid2D::from_str_with("#..\n.#.\n..#\n#.#\n", parse_tile).unwrap()
+    }
+
+    #[test]
+    fn test_from_str_with() {
+        let grid = Grid2D::from_str_with("  #.\n\n  .#  \n", parse_tile).unwrap();
+        assert_eq!(2, grid.get_width());
+        assert_eq!(2, grid.get_height());
+        assert_eq!(Some(&'#'), grid.get(&Point2D::new(1, 1)));
+        assert_eq!(None, grid.get(&Point2D::new(2, 1)));
+        assert_eq!(None, grid.get(&Point2D::new(0, -1)));
+        assert_eq!(Grid2D::new(2, 2, vec!['#', '.', '.', '#']), Some(grid));
+        assert_eq!(None, Grid2D::new(2, 2, vec!['#', '.', '.']));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid2D::from_str_with("#..\n.#.\n..##\n", parse_tile).unwrap_err();
+        assert_eq!(2, error.get_line_index());
+        assert_eq!(0, error.get_column_index());
+        assert_eq!("line 3, column 1: row length 4 differs from first row ---- \"..##\"",
+            error.to_string());
+        let error = Grid2D::from_str_with("#..\n\n  .#\n", parse_tile).unwrap_err();
+        assert_eq!(2, error.get_line_index());
+        assert_eq!(2, error.get_column_index());
+        let error = Grid2D::from_str_with("#..\n  .x.\n", parse_tile).unwrap_err();
+        assert_eq!("line 2, column 4: bad tile ---- \"x\"", error.to_string());
+        let error = error.into_parse_error(3);
+        assert_eq!(2, error.get_line());
+        assert_eq!(4, error.get_column());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let grid = sample_grid();
+        let text = grid.to_string();
+        assert_eq!("#..\n.#.\n..#\n#.#", text);
+        assert_eq!(grid, Grid2D::from_str_with(&text, parse_tile).unwrap());
+        assert_eq!("X..\n.X.\n..X\nX.X", grid.to_string_with(|c| if *c == '#' { 'X' } else { *c }));
+    }
+
+    #[test]
+    fn test_get_wrapping() {
+        let grid = sample_grid();
+        assert_eq!('#', *grid.get_wrapping(&Point2D::new(0, 0)));
+        assert_eq!('#', *grid.get_wrapping(&Point2D::new(4, 5)));
+        assert_eq!('#', *grid.get_wrapping(&Point2D::new(-1, -1)));
+        assert_eq!('.', *grid.get_wrapping(&Point2D::new(-2, -1)));
+        assert_eq!('#', *grid.get_wrapping(&Point2D::new(-300, 400)));
+    }
+
+    #[test]
+    fn test_iter_row_and_column() {
+        let grid = sample_grid();
+        assert_eq!("..#", grid.iter_row(2).collect::<String>());
+        assert_eq!("#..#", grid.iter_column(0).collect::<String>());
+        assert_eq!(".#..", grid.iter_column(1).collect::<String>());
+        assert_eq!("..##", grid.iter_column(2).collect::<String>());
+        assert_eq!(5, grid.count_matching(|c| *c == '#'));
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid2D - column 3 out of bounds")]
+    fn test_iter_column_out_of_bounds() {
+        sample_grid().iter_column(3).count();
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = sample_grid();
+        assert!(grid.set(&Point2D::new(1, 0), '#'));
+        assert!(!grid.set(&Point2D::new(3, 0), '#'));
+        assert_eq!("##.", grid.iter_row(0).collect::<String>());
+    }
+}
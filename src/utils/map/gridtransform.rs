@@ -0,0 +1,244 @@
+use super::Point2D;
+
+/// Represents one of the eight symmetries of a square (the dihedral group D4), being the four
+/// rotations by multiples of 90 degrees, each optionally preceded by a flip about the vertical
+/// axis. Transforms apply to points about the origin and to whole grids, using screen coordinates
+/// (x increasing to the right and y increasing downwards), so rotations are clockwise on screen.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct GridTransform {
+    quarter_turns: u8,
+    flipped: bool,
+}
+
+impl GridTransform {
+    /// Gets the identity transform, which leaves points and grids unchanged.
+    pub fn identity() -> Self {
+        Self {
+            quarter_turns: 0,
+            flipped: false,
+        }
+    }
+
+    /// Gets the transform rotating clockwise by the given number of quarter turns. Negative values
+    /// rotate anticlockwise.
+    pub fn rotate_clockwise(quarter_turns: i64) -> Self {
+        Self {
+            quarter_turns: quarter_turns.rem_euclid(4) as u8,
+            flipped: false,
+        }
+    }
+
+    /// Gets the transform rotating clockwise by the given angle in degrees, with negative angles
+    /// rotating anticlockwise. Returns None if the angle is not a multiple of 90 degrees.
+    pub fn from_rotation_degrees(degrees: i64) -> Option<Self> {
+        if degrees % 90 != 0 {
+            return None;
+        }
+        Some(GridTransform::rotate_clockwise(degrees / 90))
+    }
+
+    /// Gets the transform flipping about the vertical axis (left and right are swapped).
+    pub fn flip_horizontal() -> Self {
+        Self {
+            quarter_turns: 0,
+            flipped: true,
+        }
+    }
+
+    /// Gets the transform flipping about the horizontal axis (top and bottom are swapped).
+    pub fn flip_vertical() -> Self {
+        Self {
+            quarter_turns: 2,
+            flipped: true,
+        }
+    }
+
+    /// Gets the transform reflecting about the leading diagonal (x and y are swapped).
+    pub fn transpose() -> Self {
+        Self {
+            quarter_turns: 3,
+            flipped: true,
+        }
+    }
+
+    /// Gets the transform reflecting about the anti-diagonal.
+    pub fn anti_transpose() -> Self {
+        Self {
+            quarter_turns: 1,
+            flipped: true,
+        }
+    }
+
+    /// Gets all eight transforms, being the four clockwise rotations (starting with the identity)
+    /// followed by the four rotations of the horizontally-flipped orientation.
+    pub fn get_all() -> [GridTransform; 8] {
+        let mut output = [GridTransform::identity(); 8];
+        for (i, transform) in output.iter_mut().enumerate() {
+            *transform = GridTransform {
+                quarter_turns: (i % 4) as u8,
+                flipped: i >= 4,
+            };
+        }
+        output
+    }
+
+    /// Gets the number of clockwise quarter turns applied by the transform (after any flip).
+    pub fn get_quarter_turns(&self) -> u8 {
+        self.quarter_turns
+    }
+
+    /// Checks if the transform flips about the vertical axis (before any rotation).
+    pub fn is_flipped(&self) -> bool {
+        self.flipped
+    }
+
+    /// Checks if the transform swaps the width and height of a grid.
+    pub fn swaps_axes(&self) -> bool {
+        self.quarter_turns % 2 == 1
+    }
+
+    /// Gets the transform equivalent to applying the current transform followed by the other.
+    pub fn then(&self, other: &GridTransform) -> GridTransform {
+        // Flipping reverses the direction of any rotation already applied
+        let quarter_turns = if other.flipped {
+            other.quarter_turns as i64 - self.quarter_turns as i64
+        } else {
+            other.quarter_turns as i64 + self.quarter_turns as i64
+        };
+        GridTransform {
+            quarter_turns: quarter_turns.rem_euclid(4) as u8,
+            flipped: self.flipped != other.flipped,
+        }
+    }
+
+    /// Gets the transform that undoes the current transform.
+    pub fn inverse(&self) -> GridTransform {
+        // Flips (with or without rotation) are their own inverse
+        if self.flipped {
+            return *self;
+        }
+        GridTransform::rotate_clockwise(-(self.quarter_turns as i64))
+    }
+
+    /// Applies the transform to the given point, about the origin.
+    pub fn apply_to_point(&self, point: &Point2D) -> Point2D {
+        let (mut x, mut y) = (point.get_x(), point.get_y());
+        if self.flipped {
+            x = -x;
+        }
+        for _ in 0..self.quarter_turns {
+            let (new_x, new_y) = (-y, x);
+            x = new_x;
+            y = new_y;
+        }
+        Point2D::new(x, y)
+    }
+
+    /// Gets the width and height of a grid with the given width and height after the transform is
+    /// applied.
+    pub fn get_transformed_size(&self, width: usize, height: usize) -> (usize, usize) {
+        if self.swaps_axes() {
+            return (height, width);
+        }
+        (width, height)
+    }
+
+    /// Gets the mapping of cell locations for the transform applied to a whole grid with the given
+    /// width and height, for reuse across every cell of the grid.
+    pub fn get_location_map(&self, width: usize, height: usize) -> GridLocationMap {
+        let corners = [
+            Point2D::new(0, 0),
+            Point2D::new(width as i64 - 1, 0),
+            Point2D::new(0, height as i64 - 1),
+            Point2D::new(width as i64 - 1, height as i64 - 1),
+        ];
+        let transformed_corners = corners.iter().map(|corner| self.apply_to_point(corner));
+        let min_x = transformed_corners.clone().map(|corner| corner.get_x()).min().unwrap();
+        let min_y = transformed_corners.map(|corner| corner.get_y()).min().unwrap();
+        GridLocationMap {
+            x_step: self.apply_to_point(&Point2D::new(1, 0)),
+            y_step: self.apply_to_point(&Point2D::new(0, 1)),
+            offset: Point2D::new(-min_x, -min_y),
+        }
+    }
+
+    /// Determines the location (column, row) that the cell at the given location of a grid with
+    /// the given width and height moves to when the transform is applied to the whole grid. When
+    /// mapping many cells of the same grid, use the map from get_location_map instead.
+    pub fn map_location(&self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        self.get_location_map(width, height).map_location(x, y)
+    }
+}
+
+/// Represents the mapping of cell locations for a transform applied to a whole grid of a
+/// particular width and height, with the offset of the transformed grid from the origin and the
+/// movement of a single step along each axis precomputed.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct GridLocationMap {
+    x_step: Point2D,
+    y_step: Point2D,
+    offset: Point2D,
+}
+
+impl GridLocationMap {
+    /// Determines the location (column, row) that the cell at the given location of the grid moves
+    /// to when the transform is applied to the whole grid.
+    pub fn map_location(&self, x: usize, y: usize) -> (usize, usize) {
+        let loc = self.offset + self.x_step * x as i64 + self.y_step * y as i64;
+        (loc.get_x() as usize, loc.get_y() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::map::Grid2D;
+
+    #[test]
+    fn test_then_inverse_is_identity() {
+        for transform in GridTransform::get_all().iter() {
+            let inverse = transform.inverse();
+            assert_eq!(GridTransform::identity(), transform.then(&inverse));
+            assert_eq!(GridTransform::identity(), inverse.then(transform));
+        }
+    }
+
+    #[test]
+    fn test_then_matches_applying_in_turn() {
+        // Non-square grid, so that transforms swapping the axes are distinguished
+        let grid = Grid2D::new(3, 2, (0..6).collect::<Vec<u64>>()).unwrap();
+        let point = Point2D::new(2, -5);
+        for first in GridTransform::get_all().iter() {
+            for second in GridTransform::get_all().iter() {
+                let combined = first.then(second);
+                assert_eq!(grid.transform(first).transform(second), grid.transform(&combined));
+                assert_eq!(
+                    second.apply_to_point(&first.apply_to_point(&point)),
+                    combined.apply_to_point(&point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_location_map() {
+        let expected = [
+            [(0, 0), (2, 0), (0, 1), (2, 1)],
+            [(1, 0), (1, 2), (0, 0), (0, 2)],
+            [(2, 1), (0, 1), (2, 0), (0, 0)],
+            [(0, 2), (0, 0), (1, 2), (1, 0)],
+            [(2, 0), (0, 0), (2, 1), (0, 1)],
+            [(1, 2), (1, 0), (0, 2), (0, 0)],
+            [(0, 1), (2, 1), (0, 0), (2, 0)],
+            [(0, 0), (0, 2), (1, 0), (1, 2)],
+        ];
+        for (transform, expected) in GridTransform::get_all().iter().zip(expected.iter()) {
+            let location_map = transform.get_location_map(3, 2);
+            let corners = [(0, 0), (2, 0), (0, 1), (2, 1)];
+            for ((x, y), expected_loc) in corners.iter().zip(expected.iter()) {
+                assert_eq!(*expected_loc, location_map.map_location(*x, *y));
+                assert_eq!(*expected_loc, transform.map_location(*x, *y, 3, 2));
+            }
+        }
+    }
+}
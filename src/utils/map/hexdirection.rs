@@ -0,0 +1,119 @@
+/// Represents one of the six directions from a hexagonal tile to its neighbours, with the tiles
+/// arranged in horizontal rows (pointy-topped hexagons).
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    /// Gets all six hex directions, going clockwise from east.
+    pub fn get_all() -> [HexDirection; 6] {
+        [
+            HexDirection::East,
+            HexDirection::SouthEast,
+            HexDirection::SouthWest,
+            HexDirection::West,
+            HexDirection::NorthWest,
+            HexDirection::NorthEast,
+        ]
+    }
+
+    /// Gets the hex direction represented by the given abbreviation (e.g. "e" or "nw").
+    pub fn from_string(input: &str) -> Option<HexDirection> {
+        match input {
+            "e" => Some(HexDirection::East),
+            "se" => Some(HexDirection::SouthEast),
+            "sw" => Some(HexDirection::SouthWest),
+            "w" => Some(HexDirection::West),
+            "nw" => Some(HexDirection::NorthWest),
+            "ne" => Some(HexDirection::NorthEast),
+            _ => None,
+        }
+    }
+
+    /// Parses a string of concatenated hex direction abbreviations with no delimiters (e.g.
+    /// "esenee"). Returns None if the string contains anything other than valid directions.
+    pub fn parse_directions(input: &str) -> Option<Vec<HexDirection>> {
+        let mut directions: Vec<HexDirection> = vec![];
+        let mut chars = input.chars();
+        while let Some(c) = chars.next() {
+            let direction = match c {
+                'e' | 'w' => HexDirection::from_string(&c.to_string())?,
+                'n' | 's' => {
+                    let abbrev = format!("{}{}", c, chars.next()?);
+                    HexDirection::from_string(&abbrev)?
+                },
+                _ => return None,
+            };
+            directions.push(direction);
+        }
+        Some(directions)
+    }
+
+    /// Gets the direction pointing the opposite way to the current direction.
+    pub fn reverse(&self) -> HexDirection {
+        match self {
+            HexDirection::East => HexDirection::West,
+            HexDirection::SouthEast => HexDirection::NorthWest,
+            HexDirection::SouthWest => HexDirection::NorthEast,
+            HexDirection::West => HexDirection::East,
+            HexDirection::NorthWest => HexDirection::SouthEast,
+            HexDirection::NorthEast => HexDirection::SouthWest,
+        }
+    }
+
+    /// Gets the change in axial coordinates (q, r) resulting from one step in the direction.
+    pub fn get_axial_delta(&self) -> (i64, i64) {
+        match self {
+            HexDirection::East => (1, 0),
+            HexDirection::SouthEast => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::West => (-1, 0),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directions() {
+        let expected = vec![
+            HexDirection::East,
+            HexDirection::SouthEast,
+            HexDirection::NorthEast,
+            HexDirection::East,
+        ];
+        assert_eq!(Some(expected), HexDirection::parse_directions("esenee"));
+        let expected = vec![
+            HexDirection::NorthWest,
+            HexDirection::West,
+            HexDirection::SouthWest,
+        ];
+        assert_eq!(Some(expected), HexDirection::parse_directions("nwwsw"));
+        assert_eq!(Some(vec![]), HexDirection::parse_directions(""));
+    }
+
+    #[test]
+    fn test_parse_directions_rejected() {
+        for text in ["esen", "s", "nn", "ee x", "E", "enw\n", "sx"].iter() {
+            assert_eq!(None, HexDirection::parse_directions(text), "\"{}\"", text);
+        }
+    }
+
+    #[test]
+    fn test_reverse() {
+        for direction in HexDirection::get_all().iter() {
+            let (delta_q, delta_r) = direction.get_axial_delta();
+            assert_eq!((-delta_q, -delta_r), direction.reverse().get_axial_delta());
+        }
+    }
+}
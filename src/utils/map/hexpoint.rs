@@ -0,0 +1,106 @@
+use super::HexDirection;
+
+/// Represents the location of a single tile on a hexagonal grid using axial coordinates. The
+/// q-axis runs east and the r-axis runs south-east, with the implied third cube coordinate being
+/// s = -q - r.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct HexPoint {
+    q: i64,
+    r: i64,
+}
+
+impl HexPoint {
+    /// Creates a new hex point from axial coordinates.
+    pub fn new(q: i64, r: i64) -> Self {
+        Self {
+            q,
+            r,
+        }
+    }
+
+    /// Gets the value of the q-coordinate.
+    pub fn get_q(&self) -> i64 {
+        self.q
+    }
+
+    /// Gets the value of the r-coordinate.
+    pub fn get_r(&self) -> i64 {
+        self.r
+    }
+
+    /// Gets the value of the implied third cube coordinate (s = -q - r).
+    pub fn get_s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Moves the point by one step in the specified direction.
+    pub fn move_point(&mut self, direction: HexDirection) {
+        let (delta_q, delta_r) = direction.get_axial_delta();
+        self.q += delta_q;
+        self.r += delta_r;
+    }
+
+    /// Gets the neighbouring point in the specified direction.
+    pub fn get_neighbour(&self, direction: HexDirection) -> HexPoint {
+        let mut neighbour = *self;
+        neighbour.move_point(direction);
+        neighbour
+    }
+
+    /// Gets the six points surrounding the current point, going clockwise from east.
+    pub fn get_surrounding_points(&self) -> Vec<HexPoint> {
+        HexDirection::get_all()
+            .iter()
+            .map(|direction| self.get_neighbour(*direction))
+            .collect::<Vec<HexPoint>>()
+    }
+
+    /// Calculates the number of steps between the current point and the other point.
+    pub fn calculate_distance(&self, other: &HexPoint) -> u64 {
+        let d_q = (self.q - other.q).unsigned_abs();
+        let d_r = (self.r - other.r).unsigned_abs();
+        let d_s = (self.get_s() - other.get_s()).unsigned_abs();
+        (d_q + d_r + d_s) / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_follow_directions() {
+        // Directions that loop back to the starting tile
+        let mut point = HexPoint::new(0, 0);
+        for direction in HexDirection::parse_directions("nwwswee").unwrap() {
+            point.move_point(direction);
+        }
+        assert_eq!(HexPoint::new(0, 0), point);
+        let mut point = HexPoint::new(0, 0);
+        for direction in HexDirection::parse_directions("esew").unwrap() {
+            point.move_point(direction);
+        }
+        assert_eq!(HexPoint::new(0, 1), point);
+        assert_eq!(-1, point.get_s());
+    }
+
+    #[test]
+    fn test_surrounding_points() {
+        let centre = HexPoint::new(3, -2);
+        let surrounding = centre.get_surrounding_points();
+        assert_eq!(6, surrounding.len());
+        for (point, direction) in surrounding.iter().zip(HexDirection::get_all().iter()) {
+            assert_eq!(1, centre.calculate_distance(point));
+            assert_eq!(centre, point.get_neighbour(direction.reverse()));
+        }
+    }
+
+    #[test]
+    fn test_calculate_distance() {
+        let origin = HexPoint::new(0, 0);
+        assert_eq!(0, origin.calculate_distance(&origin));
+        assert_eq!(3, origin.calculate_distance(&HexPoint::new(3, -3)));
+        assert_eq!(5, origin.calculate_distance(&HexPoint::new(-2, 5)));
+        assert_eq!(4, HexPoint::new(1, 1).calculate_distance(&HexPoint::new(-1, -1)));
+    }
+}
@@ -0,0 +1,125 @@
+use super::GridTransform;
+
+/// Represents a square image tile, such as those introduced in AOC 2020 Day 20. Each tile has an
+/// ID and a square grid of pixels, with the pixel at index [0][0] being the top-left-most pixel.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ImageTile {
+    id: u64,
+    pixels: Vec<Vec<char>>,
+}
+
+impl ImageTile {
+    /// Creates a new image tile. Returns None if the given pixels do not form a non-empty square.
+    pub fn new(id: u64, pixels: Vec<Vec<char>>) -> Option<Self> {
+        if pixels.is_empty() || pixels.iter().any(|row| row.len() != pixels.len()) {
+            return None;
+        }
+        Some(Self {
+            id,
+            pixels,
+        })
+    }
+
+    /// Gets the ID of the tile.
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gets the side length of the tile, in pixels.
+    pub fn get_size(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Gets the pixels of the tile, indexed by row then column.
+    pub fn get_pixels(&self) -> &Vec<Vec<char>> {
+        &self.pixels
+    }
+
+    /// Gets the top edge of the tile, read from left to right.
+    pub fn get_top_edge(&self) -> Vec<char> {
+        self.pixels[0].clone()
+    }
+
+    /// Gets the bottom edge of the tile, read from left to right.
+    pub fn get_bottom_edge(&self) -> Vec<char> {
+        self.pixels[self.pixels.len() - 1].clone()
+    }
+
+    /// Gets the left edge of the tile, read from top to bottom.
+    pub fn get_left_edge(&self) -> Vec<char> {
+        self.pixels.iter().map(|row| row[0]).collect::<Vec<char>>()
+    }
+
+    /// Gets the right edge of the tile, read from top to bottom.
+    pub fn get_right_edge(&self) -> Vec<char> {
+        self.pixels.iter().map(|row| row[row.len() - 1]).collect::<Vec<char>>()
+    }
+
+    /// Gets all four edges of the tile in the order top, right, bottom and left.
+    pub fn get_edges(&self) -> Vec<Vec<char>> {
+        vec![
+            self.get_top_edge(),
+            self.get_right_edge(),
+            self.get_bottom_edge(),
+            self.get_left_edge(),
+        ]
+    }
+
+    /// Determines the resulting tile by conducting a single 90 degree rotation to the right (CW).
+    pub fn rotate_right_90_degrees(&self) -> ImageTile {
+        self.transform(&GridTransform::rotate_clockwise(1))
+    }
+
+    /// Determines the resulting tile by flipping the tile about its vertical axis.
+    pub fn flip_horizontal(&self) -> ImageTile {
+        self.transform(&GridTransform::flip_horizontal())
+    }
+
+    /// Gets all eight orientations of the tile, being the four rotations of the tile followed by
+    /// the four rotations of the flipped tile. The first orientation is the tile itself.
+    pub fn get_all_orientations(&self) -> Vec<ImageTile> {
+        GridTransform::get_all()
+            .iter()
+            .map(|transform| self.transform(transform))
+            .collect::<Vec<ImageTile>>()
+    }
+
+    /// Determines the resulting tile by applying the given transform to the tile.
+    pub fn transform(&self, transform: &GridTransform) -> ImageTile {
+        let size = self.get_size();
+        let mut pixels: Vec<Vec<char>> = vec![vec!['.'; size]; size];
+        let location_map = transform.get_location_map(size, size);
+        for row in 0..size {
+            for col in 0..size {
+                let (new_col, new_row) = location_map.map_location(col, row);
+                pixels[new_row][new_col] = self.pixels[row][col];
+            }
+        }
+        ImageTile {
+            id: self.id,
+            pixels,
+        }
+    }
+
+    /// Returns a copy of the tile with the outermost row and column of pixels removed from each
+    /// side. Returns None if the tile is too small to have its border removed.
+    pub fn strip_border(&self) -> Option<ImageTile> {
+        let size = self.get_size();
+        if size <= 2 {
+            return None;
+        }
+        let pixels = self.pixels[1..size - 1]
+            .iter()
+            .map(|row| row[1..size - 1].to_vec())
+            .collect::<Vec<Vec<char>>>();
+        Some(ImageTile {
+            id: self.id,
+            pixels,
+        })
+    }
+
+    /// Counts the number of pixels in the tile matching the given character.
+    pub fn count_pixels(&self, pixel: char) -> u64 {
+        self.pixels.iter().flatten().filter(|c| **c == pixel).count() as u64
+    }
+}
@@ -1,9 +1,39 @@
+mod aabb;
 mod cardinaldirection;
-mod point2d;
-mod point3d;
-mod point4d;
+mod direction;
+mod grid2d;
+mod gridtransform;
+mod hexdirection;
+mod hexpoint;
+mod imagetile;
+mod navigator;
+mod neighbourhood;
+mod point;
+mod raycast;
+mod tilejigsaw;
 
+pub use self::aabb::Aabb;
+pub use self::aabb::AabbIter;
 pub use self::cardinaldirection::CardinalDirection;
-pub use self::point2d::Point2D;
-pub use self::point3d::Point3D;
-pub use self::point4d::Point4D;
+pub use self::direction::Direction;
+pub use self::direction::RotationError;
+pub use self::grid2d::Grid2D;
+pub use self::grid2d::GridParseError;
+pub use self::gridtransform::GridLocationMap;
+pub use self::gridtransform::GridTransform;
+pub use self::hexdirection::HexDirection;
+pub use self::hexpoint::HexPoint;
+pub use self::imagetile::ImageTile;
+pub use self::navigator::Navigator;
+pub use self::navigator::NavigatorAction;
+pub use self::navigator::NavigatorMode;
+pub use self::neighbourhood::Neighbourhood;
+pub use self::neighbourhood::NeighbourhoodIter;
+pub use self::neighbourhood::NeighbourhoodShape;
+pub use self::point::ParsePointError;
+pub use self::point::Point;
+pub use self::point::Point2D;
+pub use self::point::Point3D;
+pub use self::point::Point4D;
+pub use self::raycast::RayCast;
+pub use self::tilejigsaw::TileJigsaw;
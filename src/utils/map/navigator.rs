@@ -0,0 +1,221 @@
+use super::Direction;
+use super::GridTransform;
+use super::Point2D;
+use super::RotationError;
+
+/// Represents the way in which a navigator interprets its movement actions.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum NavigatorMode {
+    /// Move actions shift the navigator itself and turns change its heading. Moving forward
+    /// follows the heading.
+    Heading,
+    /// Move actions shift the waypoint (held relative to the navigator) and turns rotate the
+    /// waypoint about the navigator. Moving forward travels to the waypoint the given number of
+    /// times.
+    Waypoint,
+}
+
+/// Represents a single action to be conducted by a navigator.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum NavigatorAction {
+    /// Move the given distance in the given direction, regardless of heading.
+    Move(Direction, i64),
+    /// Turn left (anticlockwise) by the given number of degrees.
+    TurnLeft(i64),
+    /// Turn right (clockwise) by the given number of degrees.
+    TurnRight(i64),
+    /// Move forward by the given amount.
+    Forward(i64),
+}
+
+/// Represents a navigator (or turtle) moving around a 2D plane by following a stream of actions,
+/// either by its own heading or relative to a waypoint. The positions visited by the navigator can
+/// optionally be recorded.
+pub struct Navigator {
+    mode: NavigatorMode,
+    location: Point2D,
+    heading: Direction,
+    waypoint: Point2D,
+    record_trajectory: bool,
+    trajectory: Vec<Point2D>,
+}
+
+impl Navigator {
+    /// Creates a new navigator at the given location, moving in heading mode with the given
+    /// initial heading.
+    pub fn with_heading(location: Point2D, heading: Direction) -> Self {
+        Self {
+            mode: NavigatorMode::Heading,
+            location,
+            heading,
+            waypoint: heading.get_delta(),
+            record_trajectory: false,
+            trajectory: vec![],
+        }
+    }
+
+    /// Creates a new navigator at the given location, moving in waypoint mode with the waypoint
+    /// initially at the given position relative to the navigator.
+    pub fn with_waypoint(location: Point2D, waypoint: Point2D) -> Self {
+        Self {
+            mode: NavigatorMode::Waypoint,
+            location,
+            heading: Direction::East,
+            waypoint,
+            record_trajectory: false,
+            trajectory: vec![],
+        }
+    }
+
+    /// Enables recording of the trajectory of the navigator, being its current location followed
+    /// by its location after each subsequent action that moves it.
+    pub fn enable_trajectory(&mut self) {
+        self.record_trajectory = true;
+        self.trajectory = vec![self.location];
+    }
+
+    /// Gets the trajectory recorded so far. The trajectory is empty if recording was not enabled.
+    pub fn get_trajectory(&self) -> &Vec<Point2D> {
+        &self.trajectory
+    }
+
+    /// Gets the mode in which the navigator interprets its actions.
+    pub fn get_mode(&self) -> NavigatorMode {
+        self.mode
+    }
+
+    /// Gets the current location of the navigator.
+    pub fn get_location(&self) -> Point2D {
+        self.location
+    }
+
+    /// Gets the current heading of the navigator. The heading only changes in heading mode.
+    pub fn get_heading(&self) -> Direction {
+        self.heading
+    }
+
+    /// Gets the current position of the waypoint, relative to the navigator. The waypoint only
+    /// changes in waypoint mode.
+    pub fn get_waypoint(&self) -> Point2D {
+        self.waypoint
+    }
+
+    /// Conducts the given action. Returns an error (leaving the navigator unchanged) if the action
+    /// is a turn by an unsupported angle, being a non-multiple of 45 degrees in heading mode or of
+    /// 90 degrees in waypoint mode.
+    pub fn apply(&mut self, action: &NavigatorAction) -> Result<(), RotationError> {
+        match *action {
+            NavigatorAction::Move(direction, distance) => {
+                let delta = direction.get_delta() * distance;
+                match self.mode {
+                    NavigatorMode::Heading => self.move_by(delta),
+                    NavigatorMode::Waypoint => self.waypoint += delta,
+                }
+            },
+            NavigatorAction::TurnLeft(degrees) => self.turn(-degrees)?,
+            NavigatorAction::TurnRight(degrees) => self.turn(degrees)?,
+            NavigatorAction::Forward(amount) => {
+                let delta = match self.mode {
+                    NavigatorMode::Heading => self.heading.get_delta() * amount,
+                    NavigatorMode::Waypoint => self.waypoint * amount,
+                };
+                self.move_by(delta);
+            },
+        }
+        Ok(())
+    }
+
+    /// Conducts each of the given actions in turn, stopping at the first action resulting in an
+    /// error.
+    pub fn apply_all<'a, I>(&mut self, actions: I) -> Result<(), RotationError>
+            where I: IntoIterator<Item = &'a NavigatorAction>
+    {
+        for action in actions {
+            self.apply(action)?;
+        }
+        Ok(())
+    }
+
+    /// Turns the navigator clockwise by the given number of degrees (anticlockwise if negative).
+    fn turn(&mut self, degrees: i64) -> Result<(), RotationError> {
+        match self.mode {
+            NavigatorMode::Heading => self.heading = self.heading.rotate_clockwise(degrees)?,
+            NavigatorMode::Waypoint => {
+                let rotation = match GridTransform::from_rotation_degrees(degrees) {
+                    Some(rotation) => rotation,
+                    None => return Err(RotationError::new(degrees, 90)),
+                };
+                self.waypoint = rotation.apply_to_point(&self.waypoint);
+            },
+        }
+        Ok(())
+    }
+
+    /// Moves the navigator by the given delta, recording its new location if required.
+    fn move_by(&mut self, delta: Point2D) {
+        self.location += delta;
+        if self.record_trajectory {
+            self.trajectory.push(self.location);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigator_heading_trajectory() {
+        let mut navigator = Navigator::with_heading(Point2D::new(0, 0), Direction::East);
+        navigator.enable_trajectory();
+        let actions = vec![
+            NavigatorAction::Forward(10),
+            NavigatorAction::Move(Direction::North, 3),
+            NavigatorAction::TurnRight(90),
+            NavigatorAction::Forward(2),
+            NavigatorAction::TurnLeft(45),
+            NavigatorAction::Forward(1),
+        ];
+        navigator.apply_all(&actions).unwrap();
+        assert_eq!(NavigatorMode::Heading, navigator.get_mode());
+        assert_eq!(Direction::SouthEast, navigator.get_heading());
+        assert_eq!(Point2D::new(11, 0), navigator.get_location());
+        let expected = vec![
+            Point2D::new(0, 0),
+            Point2D::new(10, 0),
+            Point2D::new(10, -3),
+            Point2D::new(10, -1),
+            Point2D::new(11, 0),
+        ];
+        assert_eq!(&expected, navigator.get_trajectory());
+    }
+
+    #[test]
+    fn test_navigator_waypoint() {
+        let mut navigator = Navigator::with_waypoint(Point2D::new(0, 0), Point2D::new(10, -1));
+        let actions = vec![
+            NavigatorAction::Forward(10),
+            NavigatorAction::Move(Direction::North, 3),
+            NavigatorAction::Forward(7),
+            NavigatorAction::TurnRight(90),
+            NavigatorAction::Forward(11),
+        ];
+        navigator.apply_all(&actions).unwrap();
+        assert_eq!(NavigatorMode::Waypoint, navigator.get_mode());
+        assert_eq!(Point2D::new(4, 10), navigator.get_waypoint());
+        assert_eq!(Point2D::new(214, 72), navigator.get_location());
+        // Turns only rotate the waypoint, leaving the heading unchanged
+        assert_eq!(Direction::East, navigator.get_heading());
+        assert!(navigator.get_trajectory().is_empty());
+    }
+
+    #[test]
+    fn test_navigator_bad_turn() {
+        let mut navigator = Navigator::with_heading(Point2D::new(0, 0), Direction::East);
+        assert!(navigator.apply(&NavigatorAction::TurnRight(30)).is_err());
+        assert_eq!(Direction::East, navigator.get_heading());
+        let mut navigator = Navigator::with_waypoint(Point2D::new(0, 0), Point2D::new(10, -1));
+        assert!(navigator.apply(&NavigatorAction::TurnLeft(45)).is_err());
+        assert_eq!(Point2D::new(10, -1), navigator.get_waypoint());
+    }
+}
@@ -0,0 +1,219 @@
+use super::Point;
+
+/// Represents the shape of a neighbourhood surrounding a point.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum NeighbourhoodShape {
+    /// Points differing by at most the radius in every coordinate (Chebyshev distance).
+    Moore,
+    /// Points whose coordinates differ by at most the radius in total (Manhattan distance).
+    VonNeumann,
+}
+
+/// Represents a neighbourhood of points surrounding a centre point, with a given shape and radius.
+/// The centre point itself is excluded unless specified otherwise.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Neighbourhood {
+    shape: NeighbourhoodShape,
+    radius: u64,
+    include_centre: bool,
+}
+
+impl Neighbourhood {
+    /// Creates a new neighbourhood of the given shape and radius, excluding the centre point.
+    pub fn new(shape: NeighbourhoodShape, radius: u64) -> Self {
+        Self {
+            shape,
+            radius,
+            include_centre: false,
+        }
+    }
+
+    /// Creates the Moore neighbourhood of radius 1, being the 3^N - 1 points differing by at most
+    /// one in each coordinate.
+    pub fn moore() -> Self {
+        Neighbourhood::new(NeighbourhoodShape::Moore, 1)
+    }
+
+    /// Creates the von Neumann neighbourhood of radius 1, being the 2N points orthogonally adjacent
+    /// to the centre point.
+    pub fn von_neumann() -> Self {
+        Neighbourhood::new(NeighbourhoodShape::VonNeumann, 1)
+    }
+
+    /// Gets a copy of the neighbourhood with the given radius.
+    pub fn with_radius(self, radius: u64) -> Self {
+        Self {
+            shape: self.shape,
+            radius,
+            include_centre: self.include_centre,
+        }
+    }
+
+    /// Gets a copy of the neighbourhood that includes the centre point.
+    pub fn with_centre(self) -> Self {
+        Self {
+            shape: self.shape,
+            radius: self.radius,
+            include_centre: true,
+        }
+    }
+
+    /// Gets the shape of the neighbourhood.
+    pub fn get_shape(&self) -> NeighbourhoodShape {
+        self.shape
+    }
+
+    /// Gets the radius of the neighbourhood.
+    pub fn get_radius(&self) -> u64 {
+        self.radius
+    }
+
+    /// Checks if the neighbourhood includes the centre point.
+    pub fn includes_centre(&self) -> bool {
+        self.include_centre
+    }
+
+    /// Checks if the given offset from the centre point lies within the neighbourhood.
+    pub fn contains_offset<const N: usize>(&self, offset: &Point<N>) -> bool {
+        let origin = Point::origin();
+        if *offset == origin {
+            return self.include_centre;
+        }
+        match self.shape {
+            NeighbourhoodShape::Moore => {
+                offset.calculate_chebyshev_distance(&origin) <= self.radius
+            },
+            NeighbourhoodShape::VonNeumann => {
+                offset.calculate_manhattan_distance(&origin) <= self.radius
+            },
+        }
+    }
+
+    /// Gets an iterator over the points in the neighbourhood surrounding the given centre point,
+    /// without allocating. Points are generated in lexicographic order of their coordinates.
+    pub fn iter<const N: usize>(&self, centre: &Point<N>) -> NeighbourhoodIter<N> {
+        let radius = self.radius as i64;
+        NeighbourhoodIter {
+            neighbourhood: *self,
+            centre: *centre,
+            offset: Some([-radius; N]),
+        }
+    }
+}
+
+/// Iterator over the points in a neighbourhood surrounding a centre point. The iterator steps
+/// through every offset within the bounding box of the neighbourhood (like an odometer), skipping
+/// those outside of the neighbourhood.
+pub struct NeighbourhoodIter<const N: usize> {
+    neighbourhood: Neighbourhood,
+    centre: Point<N>,
+    offset: Option<[i64; N]>,
+}
+
+impl<const N: usize> Iterator for NeighbourhoodIter<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Point<N>> {
+        let radius = self.neighbourhood.radius as i64;
+        loop {
+            let offset = self.offset?;
+            // Advance the offset to the next location, with the last coordinate changing fastest
+            let mut next_offset = offset;
+            self.offset = None;
+            for dim in (0..N).rev() {
+                if next_offset[dim] < radius {
+                    next_offset[dim] += 1;
+                    self.offset = Some(next_offset);
+                    break;
+                }
+                next_offset[dim] = -radius;
+            }
+            // Moore neighbourhoods fill their bounding box, so only the centre may need skipping
+            let is_centre = offset.iter().all(|d| *d == 0);
+            let is_contained = match self.neighbourhood.shape {
+                NeighbourhoodShape::Moore => true,
+                NeighbourhoodShape::VonNeumann => {
+                    offset.iter().map(|d| d.unsigned_abs()).sum::<u64>() <= radius as u64
+                },
+            };
+            if is_contained && (!is_centre || self.neighbourhood.include_centre) {
+                return Some(self.centre + Point::from_coords(offset));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::map::Point2D;
+    use crate::utils::map::Point3D;
+
+    fn count_points<const N: usize>(neighbourhood: &Neighbourhood) -> usize {
+        neighbourhood.iter(&Point::<N>::origin()).count()
+    }
+
+    #[test]
+    fn test_counts() {
+        let von_neumann = Neighbourhood::von_neumann();
+        assert_eq!(4, count_points::<2>(&von_neumann));
+        assert_eq!(6, count_points::<3>(&von_neumann));
+        assert_eq!(12, count_points::<2>(&von_neumann.with_radius(2)));
+        assert_eq!(24, count_points::<3>(&von_neumann.with_radius(2)));
+        let moore = Neighbourhood::moore();
+        assert_eq!(8, count_points::<2>(&moore));
+        assert_eq!(26, count_points::<3>(&moore));
+        assert_eq!(80, count_points::<4>(&moore));
+        assert_eq!(24, count_points::<2>(&moore.with_radius(2)));
+        assert_eq!(0, count_points::<2>(&moore.with_radius(0)));
+    }
+
+    #[test]
+    fn test_include_centre() {
+        let centre = Point2D::new(5, -3);
+        for neighbourhood in [Neighbourhood::moore(), Neighbourhood::von_neumann()].iter() {
+            assert!(!neighbourhood.includes_centre());
+            assert!(neighbourhood.iter(&centre).all(|point| point != centre));
+            let with_centre = neighbourhood.with_centre();
+            assert!(with_centre.includes_centre());
+            assert_eq!(1, with_centre.iter(&centre).filter(|point| *point == centre).count());
+            assert_eq!(
+                neighbourhood.iter(&centre).count() + 1,
+                with_centre.iter(&centre).count()
+            );
+        }
+        let single = Neighbourhood::moore().with_radius(0).with_centre();
+        assert_eq!(vec![centre], single.iter(&centre).collect::<Vec<Point2D>>());
+    }
+
+    #[test]
+    fn test_iter_order() {
+        let points = Neighbourhood::von_neumann()
+            .iter(&Point2D::new(1, 1))
+            .collect::<Vec<Point2D>>();
+        let expected = vec![
+            Point2D::new(0, 1),
+            Point2D::new(1, 0),
+            Point2D::new(1, 2),
+            Point2D::new(2, 1),
+        ];
+        assert_eq!(expected, points);
+    }
+
+    #[test]
+    fn test_contains_offset() {
+        let centre = Point3D::new(2, -1, 7);
+        for neighbourhood in [
+            Neighbourhood::von_neumann().with_radius(2),
+            Neighbourhood::moore().with_radius(2).with_centre(),
+        ].iter() {
+            for point in neighbourhood.iter(&centre) {
+                assert!(neighbourhood.contains_offset(&(point - centre)));
+            }
+        }
+        let von_neumann = Neighbourhood::von_neumann().with_radius(2);
+        assert!(von_neumann.contains_offset(&Point3D::new(1, 0, -1)));
+        assert!(!von_neumann.contains_offset(&Point3D::new(1, 1, -1)));
+        assert!(!von_neumann.contains_offset(&Point3D::new(0, 0, 0)));
+    }
+}
@@ -0,0 +1,393 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::ops::SubAssign;
+use std::str::FromStr;
+
+use super::Direction;
+use super::Neighbourhood;
+use super::NeighbourhoodIter;
+
+/// Represents a single point in N-dimensional Euclidean space with integer coordinates. Points
+/// also act as vectors, supporting addition, subtraction, negation and multiplication by a scalar.
+/// Points are ordered lexicographically by their coordinates (x first, then y, and so on).
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Point<const N: usize> {
+    coords: [i64; N],
+}
+
+/// Point in two-dimensional space, with coordinates (x, y).
+pub type Point2D = Point<2>;
+/// Point in three-dimensional space, with coordinates (x, y, z).
+pub type Point3D = Point<3>;
+/// Point in four-dimensional space, with coordinates (x, y, z, w).
+pub type Point4D = Point<4>;
+
+impl<const N: usize> Point<N> {
+    /// Creates a new point with the given coordinates.
+    pub fn from_coords(coords: [i64; N]) -> Self {
+        Self {
+            coords,
+        }
+    }
+
+    /// Creates a new point at the origin (all coordinates zero).
+    pub fn origin() -> Self {
+        Point::from_coords([0; N])
+    }
+
+    /// Gets the number of dimensions of the point.
+    pub fn get_dimensions(&self) -> usize {
+        N
+    }
+
+    /// Gets the coordinates of the point.
+    pub fn get_coords(&self) -> [i64; N] {
+        self.coords
+    }
+
+    /// Gets the value of the coordinate in the given dimension (0 for x, 1 for y and so on).
+    /// Panics if the dimension is out of range.
+    pub fn get(&self, dim: usize) -> i64 {
+        self.coords[dim]
+    }
+
+    /// Updates the value of the coordinate in the given dimension (0 for x, 1 for y and so on).
+    /// Panics if the dimension is out of range.
+    pub fn set(&mut self, dim: usize, value: i64) {
+        self.coords[dim] = value;
+    }
+
+    /// Moves the point by the amounts specified by the given delta in each dimension.
+    pub fn move_by(&mut self, delta: &Point<N>) {
+        *self += *delta;
+    }
+
+    /// Gets all of the points surrounding the current point, being every point differing by at
+    /// most one in each coordinate (excluding the current point itself). Panics if integer
+    /// overflow or underflow would occur.
+    pub fn get_surrounding_points(&self) -> Vec<Point<N>> {
+        self.iter_moore_neighbours().collect::<Vec<Point<N>>>()
+    }
+
+    /// Gets an iterator over the points in the given neighbourhood of the current point, without
+    /// allocating.
+    pub fn iter_neighbourhood(&self, neighbourhood: &Neighbourhood) -> NeighbourhoodIter<N> {
+        neighbourhood.iter(self)
+    }
+
+    /// Gets an iterator over the 3^N - 1 points differing by at most one in each coordinate from
+    /// the current point (the Moore neighbourhood), without allocating.
+    pub fn iter_moore_neighbours(&self) -> NeighbourhoodIter<N> {
+        Neighbourhood::moore().iter(self)
+    }
+
+    /// Gets an iterator over the 2N points orthogonally adjacent to the current point (the von
+    /// Neumann neighbourhood), without allocating.
+    pub fn iter_von_neumann_neighbours(&self) -> NeighbourhoodIter<N> {
+        Neighbourhood::von_neumann().iter(self)
+    }
+
+    /// Calculates the Manhattan distance between the current point and the other point, being the
+    /// sum of the absolute differences between their coordinates.
+    pub fn calculate_manhattan_distance(&self, other: &Point<N>) -> u64 {
+        self
+            .coords
+            .iter()
+            .zip(other.coords.iter())
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .sum()
+    }
+
+    /// Calculates the Chebyshev distance between the current point and the other point, being the
+    /// largest absolute difference between their coordinates.
+    pub fn calculate_chebyshev_distance(&self, other: &Point<N>) -> u64 {
+        self
+            .coords
+            .iter()
+            .zip(other.coords.iter())
+            .map(|(a, b)| (a - b).unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl Point<2> {
+    /// Creates a new 2D point.
+    pub fn new(x: i64, y: i64) -> Self {
+        Point::from_coords([x, y])
+    }
+
+    /// Moves the point by the specified amount in the x- and y-directions.
+    pub fn move_point(&mut self, delta_x: i64, delta_y: i64) {
+        self.move_by(&Self::new(delta_x, delta_y));
+    }
+
+    /// Gets the point one step away from the current point in the given direction.
+    pub fn get_neighbour(&self, direction: Direction) -> Point2D {
+        *self + direction.get_delta()
+    }
+
+    /// Gets the four points orthogonally adjacent to the current point (the von Neumann
+    /// neighbourhood), in clockwise order starting from North.
+    pub fn get_adjacent_points(&self) -> Vec<Point2D> {
+        Direction::get_cardinals()
+            .iter()
+            .map(|direction| self.get_neighbour(*direction))
+            .collect::<Vec<Point2D>>()
+    }
+
+    /// Gets the eight points orthogonally or diagonally adjacent to the current point (the Moore
+    /// neighbourhood), in clockwise order starting from North.
+    pub fn get_neighbouring_points(&self) -> Vec<Point2D> {
+        Direction::get_all()
+            .iter()
+            .map(|direction| self.get_neighbour(*direction))
+            .collect::<Vec<Point2D>>()
+    }
+}
+
+impl Point<3> {
+    /// Creates a new 3D point.
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point::from_coords([x, y, z])
+    }
+
+    /// Moves the point by the specified amount in the x-, y- and z-directions.
+    pub fn move_point(&mut self, delta_x: i64, delta_y: i64, delta_z: i64) {
+        self.move_by(&Self::new(delta_x, delta_y, delta_z));
+    }
+}
+
+impl Point<4> {
+    /// Creates a new 4D point.
+    pub fn new(x: i64, y: i64, z: i64, w: i64) -> Self {
+        Point::from_coords([x, y, z, w])
+    }
+
+    /// Moves the point by the specified amount in the x-, y-, z- and w-directions.
+    pub fn move_point(&mut self, delta_x: i64, delta_y: i64, delta_z: i64, delta_w: i64) {
+        self.move_by(&Self::new(delta_x, delta_y, delta_z, delta_w));
+    }
+}
+
+/// Implements the getter and setter for a named coordinate, for each of the given point types
+/// that have that coordinate.
+macro_rules! impl_named_coord {
+    ($getter:ident, $setter:ident, $name:literal, $dim:literal, $($n:literal),+) => {
+        $(
+            impl Point<$n> {
+                #[doc = concat!("Gets the value of the ", $name, "-coordinate.")]
+                pub fn $getter(&self) -> i64 {
+                    return self.coords[$dim];
+                }
+
+                #[doc = concat!("Updates the value of the ", $name, "-coordinate.")]
+                pub fn $setter(&mut self, value: i64) {
+                    self.coords[$dim] = value;
+                }
+            }
+        )+
+    };
+}
+
+impl_named_coord!(get_x, set_x, "x", 0, 2, 3, 4);
+impl_named_coord!(get_y, set_y, "y", 1, 2, 3, 4);
+impl_named_coord!(get_z, set_z, "z", 2, 3, 4);
+impl_named_coord!(get_w, set_w, "w", 3, 4);
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, dim: usize) -> &i64 {
+        &self.coords[dim]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, dim: usize) -> &mut i64 {
+        &mut self.coords[dim]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Point<N> {
+        let mut coords = self.coords;
+        for (coord, other_coord) in coords.iter_mut().zip(other.coords.iter()) {
+            *coord += other_coord;
+        }
+        Point::from_coords(coords)
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Point<N> {
+        self + -other
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Point<N> {
+        self * -1
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, scalar: i64) -> Point<N> {
+        let mut coords = self.coords;
+        for coord in coords.iter_mut() {
+            *coord *= scalar;
+        }
+        Point::from_coords(coords)
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    /// Formats the point as its comma-separated coordinates in parentheses, e.g. "(1, -2, 3)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let coords = self.coords.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+        write!(f, "({})", coords.join(", "))
+    }
+}
+
+/// Error returned when a point cannot be parsed from a string.
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
+pub struct ParsePointError {
+    text: String,
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid point ---- \"{}\"", self.text)
+    }
+}
+
+impl Error for ParsePointError {}
+
+impl<const N: usize> FromStr for Point<N> {
+    type Err = ParsePointError;
+
+    /// Parses a point from its comma-separated coordinates, optionally enclosed in parentheses and
+    /// surrounded by whitespace (e.g. "1,-2,3" or "(1, -2, 3)"). The number of coordinates must
+    /// match the number of dimensions of the point.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePointError { text: s.to_string() };
+        let mut inner = s.trim();
+        if inner.starts_with('(') && inner.ends_with(')') {
+            inner = &inner[1..inner.len() - 1];
+        }
+        let mut coords = [0; N];
+        let mut count = 0;
+        for raw_coord in inner.split(',') {
+            if count >= N {
+                return Err(error());
+            }
+            coords[count] = raw_coord.trim().parse::<i64>().map_err(|_| error())?;
+            count += 1;
+        }
+        if count != N {
+            return Err(error());
+        }
+        Ok(Point::from_coords(coords))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators() {
+        let a = Point3D::new(1, -2, 3);
+        let b = Point3D::new(4, 5, -6);
+        assert_eq!(Point3D::new(5, 3, -3), a + b);
+        assert_eq!(Point3D::new(-3, -7, 9), a - b);
+        assert_eq!(Point3D::new(-1, 2, -3), -a);
+        assert_eq!(Point3D::new(3, -6, 9), a * 3);
+        let mut c = a;
+        c += b;
+        assert_eq!(a + b, c);
+        c -= b;
+        assert_eq!(a, c);
+        c[2] = 10;
+        assert_eq!(10, c.get_z());
+        assert_eq!(-2, c[1]);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Ok(Point3D::new(1, -2, 3)), "1,-2,3".parse::<Point3D>());
+        assert_eq!(Ok(Point3D::new(1, -2, 3)), " (1, -2, 3) ".parse::<Point3D>());
+        assert_eq!(Ok(Point2D::new(0, 7)), "(0,7)".parse::<Point2D>());
+    }
+
+    #[test]
+    fn test_from_str_rejected() {
+        for text in ["", "1,2", "1,2,3,4", "1,,3", "(1, 2, 3", "1, x, 3", "[1, 2, 3]"].iter() {
+            let error = text.parse::<Point3D>().unwrap_err();
+            assert_eq!(format!("invalid point ---- \"{}\"", text), error.to_string());
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let point = Point4D::new(1, -2, 3, 0);
+        assert_eq!("(1, -2, 3, 0)", point.to_string());
+        assert_eq!(Ok(point), point.to_string().parse::<Point4D>());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut points = vec![
+            Point2D::new(1, 0),
+            Point2D::new(0, 5),
+            Point2D::new(1, -1),
+            Point2D::new(0, -5),
+        ];
+        points.sort();
+        let expected = vec![
+            Point2D::new(0, -5),
+            Point2D::new(0, 5),
+            Point2D::new(1, -1),
+            Point2D::new(1, 0),
+        ];
+        assert_eq!(expected, points);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3D::new(1, -2, 3);
+        let b = Point3D::new(-3, 5, 3);
+        assert_eq!(11, a.calculate_manhattan_distance(&b));
+        assert_eq!(7, a.calculate_chebyshev_distance(&b));
+        assert_eq!(0, a.calculate_manhattan_distance(&a));
+        assert_eq!(0, a.calculate_chebyshev_distance(&a));
+        assert_eq!(a.calculate_manhattan_distance(&b), b.calculate_manhattan_distance(&a));
+    }
+}
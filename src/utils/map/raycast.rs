@@ -0,0 +1,173 @@
+use super::Grid2D;
+use super::Point2D;
+
+/// Represents a line-of-sight query on a 2D grid. Rays are cast from a start point, stepping by a
+/// direction (delta) vector at a time, until reaching a cell matching a "stop when" predicate. By
+/// default, rays stop at the edge of the grid and have no limit on the distance travelled.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RayCast {
+    max_distance: Option<u64>,
+    wrapping: bool,
+}
+
+impl RayCast {
+    /// Creates a new ray-casting query, stopping at the edge of the grid with no distance limit.
+    pub fn new() -> Self {
+        Self {
+            max_distance: None,
+            wrapping: false,
+        }
+    }
+
+    /// Limits rays to travelling at most the given number of steps from the start point.
+    pub fn with_max_distance(self, max_distance: u64) -> Self {
+        Self {
+            max_distance: Some(max_distance),
+            wrapping: self.wrapping,
+        }
+    }
+
+    /// Allows rays to wrap around to the opposite edge of the grid instead of stopping at the
+    /// edge. Rays without a distance limit stop once they return to the start point.
+    pub fn with_wrapping(self) -> Self {
+        Self {
+            max_distance: self.max_distance,
+            wrapping: true,
+        }
+    }
+
+    /// Casts a single ray from the start point (excluded) in the given direction, returning the
+    /// location of the first cell for which the predicate is true. Returns None if the ray leaves
+    /// the grid, exceeds the maximum distance or returns to the start point before a match is
+    /// found, or if the direction is zero.
+    pub fn cast<T, P>(&self, grid: &Grid2D<T>, start: &Point2D, direction: &Point2D, stop_when: P)
+            -> Option<Point2D>
+            where P: Fn(&Point2D, &T) -> bool
+    {
+        if *direction == Point2D::origin() || grid.get_width() == 0 || grid.get_height() == 0 {
+            return None;
+        }
+        let start = if self.wrapping { wrap_point(grid, start) } else { *start };
+        let mut loc = start;
+        let mut distance = 0;
+        loop {
+            if self.max_distance.is_some_and(|max_distance| distance >= max_distance) {
+                return None;
+            }
+            loc += *direction;
+            distance += 1;
+            if self.wrapping {
+                loc = wrap_point(grid, &loc);
+                if loc == start {
+                    return None;
+                }
+            }
+            match grid.get(&loc) {
+                Some(cell) if stop_when(&loc, cell) => return Some(loc),
+                Some(_) => (),
+                None => return None,
+            }
+        }
+    }
+
+    /// Casts a ray from the start point in each of the given directions, returning the first hit
+    /// (if any) for each direction in the same order as the directions.
+    pub fn cast_all<T, P>(&self, grid: &Grid2D<T>, start: &Point2D, directions: &[Point2D],
+            stop_when: P) -> Vec<Option<Point2D>>
+            where P: Fn(&Point2D, &T) -> bool
+    {
+        directions
+            .iter()
+            .map(|direction| self.cast(grid, start, direction, &stop_when))
+            .collect::<Vec<Option<Point2D>>>()
+    }
+}
+
+impl Default for RayCast {
+    fn default() -> Self {
+        RayCast::new()
+    }
+}
+
+/// Wraps the given point around the edges of the grid so that it lies within the grid.
+fn wrap_point<T>(grid: &Grid2D<T>, loc: &Point2D) -> Point2D {
+    Point2D::new(
+        loc.get_x().rem_euclid(grid.get_width() as i64),
+        loc.get_y().rem_euclid(grid.get_height() as i64),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_grid() -> Grid2D<char> {
+        Grid2D::from_str_with("#....\n.....\n.....\n.....\n...#.\n", Ok::<char, String>).unwrap()
+    }
+
+    fn is_occupied(_loc: &Point2D, c: &char) -> bool {
+        *c == '#'
+    }
+
+    #[test]
+    fn test_cast() {
+        let grid = sample_grid();
+        let start = Point2D::new(2, 2);
+        let directions = vec![
+            Point2D::new(-1, -1),
+            Point2D::new(1, 0),
+            Point2D::new(1, 2),
+            Point2D::new(0, 0),
+        ];
+        let hits = RayCast::new().cast_all(&grid, &start, &directions, is_occupied);
+        assert_eq!(vec![Some(Point2D::new(0, 0)), None, Some(Point2D::new(3, 4)), None], hits);
+        // Start point itself is never reported as a hit
+        let hit = RayCast::new().cast(&grid, &Point2D::new(0, 0), &Point2D::new(1, 1), is_occupied);
+        assert_eq!(None, hit);
+    }
+
+    #[test]
+    fn test_cast_max_distance() {
+        let grid = sample_grid();
+        let start = Point2D::new(4, 4);
+        let direction = Point2D::new(-1, -1);
+        let ray_cast = RayCast::new().with_max_distance(3);
+        assert_eq!(None, ray_cast.cast(&grid, &start, &direction, is_occupied));
+        let ray_cast = RayCast::new().with_max_distance(4);
+        let hit = ray_cast.cast(&grid, &start, &direction, is_occupied);
+        assert_eq!(Some(Point2D::new(0, 0)), hit);
+        let ray_cast = RayCast::new().with_max_distance(0);
+        assert_eq!(None, ray_cast.cast(&grid, &start, &direction, |_, _| true));
+    }
+
+    #[test]
+    fn test_cast_wrapping() {
+        let grid = sample_grid();
+        let ray_cast = RayCast::new().with_wrapping();
+        // Ray leaves the right edge and comes back in from the left
+        let hit = ray_cast.cast(&grid, &Point2D::new(2, 0), &Point2D::new(1, 0), is_occupied);
+        assert_eq!(Some(Point2D::new(0, 0)), hit);
+        // Start point outside the grid is wrapped into the grid first
+        let hit = ray_cast.cast(&grid, &Point2D::new(-3, 9), &Point2D::new(1, 0), is_occupied);
+        assert_eq!(Some(Point2D::new(3, 4)), hit);
+        let ray_cast = ray_cast.with_max_distance(2);
+        let hit = ray_cast.cast(&grid, &Point2D::new(2, 0), &Point2D::new(1, 0), is_occupied);
+        assert_eq!(None, hit);
+    }
+
+    #[test]
+    fn test_cast_wrapping_returns_to_start() {
+        let grid = sample_grid();
+        let ray_cast = RayCast::new().with_wrapping();
+        // No match along the row, so the ray stops once back at the start instead of looping
+        let hit = ray_cast.cast(&grid, &Point2D::new(2, 1), &Point2D::new(1, 0), is_occupied);
+        assert_eq!(None, hit);
+        // The matching start cell is not reported, as the ray stops on returning to it
+        let hit = ray_cast.cast(&grid, &Point2D::new(0, 0), &Point2D::new(0, 1), is_occupied);
+        assert_eq!(None, hit);
+        let hit = ray_cast.cast(&grid, &Point2D::new(0, 0), &Point2D::new(0, 1), |loc, _| {
+            loc.get_y() == 4
+        });
+        assert_eq!(Some(Point2D::new(0, 4)), hit);
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use super::ImageTile;
+
+/// Represents a jigsaw of square image tiles that are to be arranged into a square image, with
+/// each pair of adjacent tiles having matching edges. Tiles may be rotated and flipped in order to
+/// fit into the assembled image.
+pub struct TileJigsaw {
+    tiles: Vec<ImageTile>,
+}
+
+impl TileJigsaw {
+    /// Creates a new jigsaw from the given tiles.
+    pub fn new(tiles: &[ImageTile]) -> Self {
+        Self {
+            tiles: tiles.to_vec(),
+        }
+    }
+
+    /// Gets the number of tiles along each side of the assembled image. Returns None if the number
+    /// of tiles is not a square number.
+    pub fn get_side_length(&self) -> Option<usize> {
+        let side = (self.tiles.len() as f64).sqrt().round() as usize;
+        if side * side != self.tiles.len() {
+            return None;
+        }
+        Some(side)
+    }
+
+    /// Finds the IDs of the tiles that must be located at the corners of the assembled image,
+    /// being the tiles that have exactly two edges that do not match an edge of any other tile
+    /// under any orientation.
+    pub fn find_corner_tile_ids(&self) -> Vec<u64> {
+        // Count the number of tiles in which each edge occurs, in either reading direction
+        let mut edge_counts: HashMap<Vec<char>, u64> = HashMap::new();
+        for tile in self.tiles.iter() {
+            let mut tile_edges: HashSet<Vec<char>> = HashSet::new();
+            for edge in tile.get_edges() {
+                let mut reversed = edge.clone();
+                reversed.reverse();
+                tile_edges.insert(edge);
+                tile_edges.insert(reversed);
+            }
+            for edge in tile_edges {
+                *edge_counts.entry(edge).or_insert(0) += 1;
+            }
+        }
+        // Corner tiles have two edges that are not shared with any other tile
+        let mut corner_ids: Vec<u64> = vec![];
+        for tile in self.tiles.iter() {
+            let unmatched = tile
+                .get_edges()
+                .iter()
+                .filter(|edge| *edge_counts.get(*edge).unwrap() == 1)
+                .count();
+            if unmatched == 2 {
+                corner_ids.push(tile.get_id());
+            }
+        }
+        corner_ids
+    }
+
+    /// Arranges the tiles into a square grid, with each tile oriented so that its edges match
+    /// those of all adjacent tiles. Rows of the returned grid run from top to bottom, with tiles in
+    /// each row running from left to right. Returns None if no valid arrangement exists.
+    pub fn assemble(&self) -> Option<Vec<Vec<ImageTile>>> {
+        let side = self.get_side_length()?;
+        if side == 0 {
+            return None;
+        }
+        let orientations = self
+            .tiles
+            .iter()
+            .map(|tile| tile.get_all_orientations())
+            .collect::<Vec<Vec<ImageTile>>>();
+        let mut used = vec![false; self.tiles.len()];
+        let mut placed: Vec<ImageTile> = vec![];
+        if !self.place_next_tile(side, &orientations, &mut used, &mut placed) {
+            return None;
+        }
+        let grid = placed
+            .chunks(side)
+            .map(|row| row.to_vec())
+            .collect::<Vec<Vec<ImageTile>>>();
+        Some(grid)
+    }
+
+    /// Assembles the jigsaw and joins the tiles into a single image, with the border of each tile
+    /// removed before joining. The ID of the resulting image is 0. Returns None if the jigsaw could
+    /// not be assembled.
+    pub fn assemble_image(&self) -> Option<ImageTile> {
+        let grid = self.assemble()?;
+        let mut pixels: Vec<Vec<char>> = vec![];
+        for tile_row in grid {
+            let stripped = tile_row
+                .iter()
+                .map(|tile| tile.strip_border())
+                .collect::<Option<Vec<ImageTile>>>()?;
+            for i in 0..stripped[0].get_size() {
+                let mut pixel_row: Vec<char> = vec![];
+                for tile in stripped.iter() {
+                    pixel_row.extend(tile.get_pixels()[i].iter());
+                }
+                pixels.push(pixel_row);
+            }
+        }
+        ImageTile::new(0, pixels)
+    }
+
+    /// Recursively places tiles into the next free location in the grid (filled row by row),
+    /// backtracking if no remaining tile fits. Returns true if all tiles have been placed.
+    fn place_next_tile(&self, side: usize, orientations: &[Vec<ImageTile>],
+            used: &mut Vec<bool>, placed: &mut Vec<ImageTile>) -> bool
+    {
+        if placed.len() == self.tiles.len() {
+            return true;
+        }
+        let index = placed.len();
+        let left_edge = if !index.is_multiple_of(side) {
+            Some(placed[index - 1].get_right_edge())
+        } else {
+            None
+        };
+        let top_edge = if index >= side {
+            Some(placed[index - side].get_bottom_edge())
+        } else {
+            None
+        };
+        for i in 0..self.tiles.len() {
+            if used[i] {
+                continue;
+            }
+            for tile in orientations[i].iter() {
+                // Check the candidate orientation fits against the tiles to the left and above
+                if let Some(edge) = &left_edge {
+                    if tile.get_left_edge() != *edge {
+                        continue;
+                    }
+                }
+                if let Some(edge) = &top_edge {
+                    if tile.get_top_edge() != *edge {
+                        continue;
+                    }
+                }
+                used[i] = true;
+                placed.push(tile.clone());
+                if self.place_next_tile(side, orientations, used, placed) {
+                    return true;
+                }
+                placed.pop();
+                used[i] = false;
+            }
+        }
+        false
+    }
+}
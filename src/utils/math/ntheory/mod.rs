@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 //
 // Chinese Remainder Theorem (CRT) solving functions humbly borrowed from Rosetta Code.
 // 
@@ -5,7 +7,7 @@
 // 
 
 /// Solver for Chinese remainder theorem (CRT) using the provided modulii and residues.
-pub fn solve_crt(residues: &Vec<i64>, modulii: &Vec<i64>) -> Option<i64> {
+pub fn solve_crt(residues: &[i64], modulii: &[i64]) -> Option<i64> {
     let prod = modulii.iter().product::<i64>();
     let mut sum = 0;
     for (&residue, &modulus) in residues.iter().zip(modulii) {
@@ -32,3 +34,102 @@ fn mod_inv(x: i64, n: i64) -> Option<i64> {
         None
     }
 }
+
+/// Calculates the result of raising the base to the given exponent, modulo the given modulus.
+/// Returns None if the modulus is not positive.
+pub fn mod_pow(base: i64, exponent: u64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    if modulus == 1 {
+        return Some(0);
+    }
+    let modulus = modulus as i128;
+    let mut result: i128 = 1;
+    let mut base = (base as i128).rem_euclid(modulus);
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    Some(result as i64)
+}
+
+/// Finds the smallest non-negative exponent x such that base^x is congruent to the target modulo
+/// the given modulus, using the baby-step giant-step algorithm. Returns None if the modulus is not
+/// positive or no such exponent exists. A base that is not invertible modulo the modulus only has
+/// its zero exponent checked.
+pub fn discrete_log(base: i64, target: i64, modulus: i64) -> Option<u64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let base = base.rem_euclid(modulus);
+    let target = target.rem_euclid(modulus);
+    if target == 1 % modulus {
+        return Some(0);
+    }
+    let m = (modulus as f64).sqrt().ceil() as u64;
+    // Baby steps - record the smallest j for each value of base^j, for j in [0, m)
+    let mut baby_steps: HashMap<i64, u64> = HashMap::new();
+    let mut value = 1 % modulus;
+    for j in 0..m {
+        baby_steps.entry(value).or_insert(j);
+        value = ((value as i128 * base as i128) % modulus as i128) as i64;
+    }
+    // Giant steps - multiply target by base^(-m) until a baby step value is found
+    let factor = mod_inv(mod_pow(base, m, modulus)?, modulus)?;
+    let mut gamma = target;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma = ((gamma as i128 * factor as i128) % modulus as i128) as i64;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(Some(4), mod_pow(2, 10, 1020));
+        assert_eq!(Some(1), mod_pow(5, 0, 13));
+        assert_eq!(Some(0), mod_pow(5, 3, 1));
+        // Negative bases are reduced into the range of the modulus
+        assert_eq!(Some(6), mod_pow(-1, 3, 7));
+        assert_eq!(Some(1), mod_pow(-1, 4, 7));
+        // Intermediate products must not overflow
+        assert_eq!(Some(1), mod_pow(3, 1_000_000_006, 1_000_000_007));
+        assert_eq!(None, mod_pow(2, 3, 0));
+        assert_eq!(None, mod_pow(2, 3, -5));
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(Some(8), discrete_log(7, 5764801, 20201227));
+        assert_eq!(Some(4), discrete_log(3, 13, 17));
+        assert_eq!(None, discrete_log(2, 3, 7));
+        for x in 0..16 {
+            let target = mod_pow(3, x, 17).unwrap();
+            assert_eq!(Some(x), discrete_log(3, target, 17));
+        }
+    }
+
+    #[test]
+    fn test_discrete_log_normalised() {
+        assert_eq!(Some(1), discrete_log(-1, 6, 7));
+        assert_eq!(Some(1), discrete_log(-1, -1, 7));
+        assert_eq!(Some(2), discrete_log(10, 2, 7));
+        // Zero exponent is found even when the base is not invertible
+        assert_eq!(Some(0), discrete_log(2, 1, 4));
+        assert_eq!(Some(0), discrete_log(2, 5, 4));
+        assert_eq!(None, discrete_log(2, 0, 4));
+        assert_eq!(None, discrete_log(2, 1, 0));
+        assert_eq!(Some(0), discrete_log(2, 3, 1));
+    }
+}
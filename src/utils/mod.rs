@@ -1,3 +1,6 @@
+pub mod collections;
 pub mod machines;
 pub mod math;
 pub mod map;
+pub mod render;
+pub mod search;
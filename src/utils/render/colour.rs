@@ -0,0 +1,43 @@
+/// Represents a colour with 8-bit red, green and blue components.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Rgb {
+    /// Creates a new colour from the given red, green and blue components.
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+        }
+    }
+
+    /// Creates a new shade of grey with the given brightness (0 for black, 255 for white).
+    pub fn grey(brightness: u8) -> Self {
+        Rgb::new(brightness, brightness, brightness)
+    }
+
+    /// Gets the red component of the colour.
+    pub fn get_red(&self) -> u8 {
+        self.red
+    }
+
+    /// Gets the green component of the colour.
+    pub fn get_green(&self) -> u8 {
+        self.green
+    }
+
+    /// Gets the blue component of the colour.
+    pub fn get_blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Gets the components of the colour in the order red, green, blue.
+    pub fn to_bytes(self) -> [u8; 3] {
+        [self.red, self.green, self.blue]
+    }
+}
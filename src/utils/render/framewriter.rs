@@ -0,0 +1,79 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Represents the file format of a rendered frame.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum FrameFormat {
+    Ascii,
+    Ppm,
+    Pgm,
+}
+
+impl FrameFormat {
+    /// Parses a frame format from its name ("ascii", "ppm" or "pgm"), ignoring case.
+    pub fn from_name(name: &str) -> Option<FrameFormat> {
+        match name.to_ascii_lowercase().as_str() {
+            "ascii" | "txt" => Some(FrameFormat::Ascii),
+            "ppm" => Some(FrameFormat::Ppm),
+            "pgm" => Some(FrameFormat::Pgm),
+            _ => None,
+        }
+    }
+
+    /// Gets the file extension used for frames of the format.
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            FrameFormat::Ascii => "txt",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Pgm => "pgm",
+        }
+    }
+}
+
+/// Writes a sequence of rendered frames to numbered files in a directory, so that the frames can
+/// be assembled into an animation offline. Frames are named "<prefix>_0000.<ext>", with the number
+/// counting up from zero.
+pub struct FrameWriter {
+    directory: PathBuf,
+    prefix: String,
+    format: FrameFormat,
+    frame_count: usize,
+}
+
+impl FrameWriter {
+    /// Creates a new frame writer for frames of the given format, creating the output directory
+    /// (and its parents) if it does not already exist.
+    pub fn new(directory: &Path, prefix: &str, format: FrameFormat) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            prefix: prefix.to_string(),
+            format,
+            frame_count: 0,
+        })
+    }
+
+    /// Gets the format of the frames written.
+    pub fn get_format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// Gets the number of frames written so far.
+    pub fn get_frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Writes the contents of the next frame to its own file, returning the path of the file.
+    pub fn write_frame(&mut self, contents: &[u8]) -> io::Result<PathBuf> {
+        let file_name = format!(
+            "{}_{:04}.{}",
+            self.prefix, self.frame_count, self.format.get_extension()
+        );
+        let path = self.directory.join(file_name);
+        fs::write(&path, contents)?;
+        self.frame_count += 1;
+        Ok(path)
+    }
+}
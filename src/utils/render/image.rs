@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use super::Rgb;
+use crate::utils::map::Aabb;
+use crate::utils::map::Grid2D;
+use crate::utils::map::Point2D;
+
+/// Converts the cells of a sparse map lying within the given bounds into a dense grid, with the
+/// given function mapping each location's value (or None for locations missing from the map) to
+/// a grid cell. The top-left cell of the grid corresponds to the minimum corner of the bounds.
+pub fn sparse_to_grid<T, U, F>(map: &HashMap<Point2D, T>, bounds: &Aabb<2>, to_cell: F)
+        -> Grid2D<U>
+        where F: Fn(Option<&T>) -> U
+{
+    let [width, height] = bounds.get_size();
+    let min = bounds.get_min();
+    let mut cells: Vec<U> = Vec::with_capacity((width * height) as usize);
+    for y in 0..height as i64 {
+        for x in 0..width as i64 {
+            let loc = Point2D::new(min.get_x() + x, min.get_y() + y);
+            cells.push(to_cell(map.get(&loc)));
+        }
+    }
+    Grid2D::new(width as usize, height as usize, cells).unwrap()
+}
+
+/// Renders the cells of a sparse map lying within the given bounds as ASCII text, with each row
+/// on its own line. The given function maps each location's value (or None for locations missing
+/// from the map) to a character.
+pub fn render_sparse_ascii<T, F>(map: &HashMap<Point2D, T>, bounds: &Aabb<2>, to_char: F)
+        -> String
+        where F: Fn(Option<&T>) -> char
+{
+    sparse_to_grid(map, bounds, to_char).to_string_with(|c| *c)
+}
+
+/// Encodes the grid as a binary PPM (P6) image, with each cell drawn as a square of the given
+/// number of pixels (at least one) in the colour given by the mapping function.
+pub fn encode_ppm<T, F>(grid: &Grid2D<T>, scale: usize, to_colour: F) -> Vec<u8>
+        where F: Fn(&T) -> Rgb
+{
+    encode_netpbm(grid, scale, "P6", |cell| to_colour(cell).to_bytes().to_vec())
+}
+
+/// Encodes the grid as a binary PGM (P5) image, with each cell drawn as a square of the given
+/// number of pixels (at least one) in the shade of grey given by the mapping function (0 for
+/// black, 255 for white).
+pub fn encode_pgm<T, F>(grid: &Grid2D<T>, scale: usize, to_grey: F) -> Vec<u8>
+        where F: Fn(&T) -> u8
+{
+    encode_netpbm(grid, scale, "P5", |cell| vec![to_grey(cell)])
+}
+
+/// Encodes the grid as a binary Netpbm image with the given magic number, using the mapping
+/// function to get the bytes of the pixels drawn for each cell.
+fn encode_netpbm<T, F>(grid: &Grid2D<T>, scale: usize, magic: &str, to_pixel: F) -> Vec<u8>
+        where F: Fn(&T) -> Vec<u8>
+{
+    let scale = scale.max(1);
+    let header = format!(
+        "{}\n{} {}\n255\n",
+        magic, grid.get_width() * scale, grid.get_height() * scale
+    );
+    let mut output = header.into_bytes();
+    for row in grid.iter_rows() {
+        let pixels = row.iter().map(&to_pixel).collect::<Vec<Vec<u8>>>();
+        for _ in 0..scale {
+            for pixel in pixels.iter() {
+                for _ in 0..scale {
+                    output.extend_from_slice(pixel);
+                }
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_map() -> HashMap<Point2D, u8> {
+        let mut map: HashMap<Point2D, u8> = HashMap::new();
+        map.insert(Point2D::new(-1, 2), 1);
+        map.insert(Point2D::new(1, 3), 2);
+        map.insert(Point2D::new(5, 5), 3);
+        map
+    }
+
+    fn to_char(value: Option<&u8>) -> char {
+        match value {
+            Some(value) => (b'0' + value) as char,
+            None => '.',
+        }
+    }
+
+    #[test]
+    fn test_sparse_to_grid() {
+        let bounds = Aabb::new(&Point2D::new(-1, 2), &Point2D::new(1, 3));
+        let grid = sparse_to_grid(&sample_map(), &bounds, |value| value.copied());
+        assert_eq!(3, grid.get_width());
+        assert_eq!(2, grid.get_height());
+        assert_eq!(Some(&Some(1)), grid.get(&Point2D::new(0, 0)));
+        assert_eq!(Some(&Some(2)), grid.get(&Point2D::new(2, 1)));
+        assert_eq!(Some(&None), grid.get(&Point2D::new(1, 0)));
+    }
+
+    #[test]
+    fn test_render_sparse_ascii() {
+        let map = sample_map();
+        let bounds = Aabb::from_points(map.keys().copied()).unwrap();
+        let expected = "1......\n..2....\n.......\n......3";
+        assert_eq!(expected, render_sparse_ascii(&map, &bounds, to_char));
+        // Bounds may cover only part of the map
+        let bounds = Aabb::new(&Point2D::new(0, 3), &Point2D::new(2, 3));
+        assert_eq!(".2.", render_sparse_ascii(&map, &bounds, to_char));
+    }
+
+    #[test]
+    fn test_encode_ppm() {
+        let grid = Grid2D::new(2, 1, vec![true, false]).unwrap();
+        let to_colour = |on: &bool| if *on { Rgb::new(255, 0, 10) } else { Rgb::grey(7) };
+        let mut expected = b"P6\n2 1\n255\n".to_vec();
+        expected.extend_from_slice(&[255, 0, 10, 7, 7, 7]);
+        assert_eq!(expected, encode_ppm(&grid, 1, to_colour));
+        // Each cell is drawn as a square of pixels, row by row
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend_from_slice(&[255, 0, 10, 255, 0, 10, 7, 7, 7, 7, 7, 7]);
+        }
+        assert_eq!(expected, encode_ppm(&grid, 2, to_colour));
+    }
+
+    #[test]
+    fn test_encode_pgm() {
+        let grid = Grid2D::new(2, 2, vec![0, 1, 2, 3]).unwrap();
+        let to_grey = |value: &u8| value * 80;
+        let mut expected = b"P5\n2 2\n255\n".to_vec();
+        expected.extend_from_slice(&[0, 80, 160, 240]);
+        assert_eq!(expected, encode_pgm(&grid, 1, to_grey));
+        // Scale of zero is treated as one
+        assert_eq!(expected, encode_pgm(&grid, 0, to_grey));
+        let mut expected = b"P5\n6 6\n255\n".to_vec();
+        for row in [[0, 80], [160, 240]].iter() {
+            for _ in 0..3 {
+                expected.extend_from_slice(&[row[0]; 3]);
+                expected.extend_from_slice(&[row[1]; 3]);
+            }
+        }
+        assert_eq!(expected, encode_pgm(&grid, 3, to_grey));
+    }
+}
@@ -0,0 +1,11 @@
+mod colour;
+mod framewriter;
+mod image;
+
+pub use self::colour::Rgb;
+pub use self::framewriter::FrameFormat;
+pub use self::framewriter::FrameWriter;
+pub use self::image::encode_pgm;
+pub use self::image::encode_ppm;
+pub use self::image::render_sparse_ascii;
+pub use self::image::sparse_to_grid;
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use crate::utils::map::Grid2D;
+use crate::utils::map::Point2D;
+
+/// Gets a neighbours function for searching the given grid, where each location is connected to
+/// the orthogonally adjacent locations within the grid whose cells are passable.
+pub fn grid_neighbours<'a, T, P>(grid: &'a Grid2D<T>, passable: P)
+        -> impl Fn(&Point2D) -> Vec<Point2D> + 'a
+        where P: Fn(&T) -> bool + 'a
+{
+    move |loc: &Point2D| {
+        loc.get_adjacent_points()
+            .into_iter()
+            .filter(|next_loc| grid.get(next_loc).is_some_and(&passable))
+            .collect::<Vec<Point2D>>()
+    }
+}
+
+/// Gets a weighted neighbours function for searching the given grid, where each location is
+/// connected to the orthogonally adjacent locations within the grid. The cost function gives the
+/// cost of entering a cell, or None if the cell is impassable.
+pub fn grid_weighted_neighbours<'a, T, C>(grid: &'a Grid2D<T>, cost: C)
+        -> impl Fn(&Point2D) -> Vec<(Point2D, u64)> + 'a
+        where C: Fn(&T) -> Option<u64> + 'a
+{
+    move |loc: &Point2D| {
+        loc.get_adjacent_points()
+            .into_iter()
+            .filter_map(|next_loc| Some((next_loc, cost(grid.get(&next_loc)?)?)))
+            .collect::<Vec<(Point2D, u64)>>()
+    }
+}
+
+/// Gets a neighbours function for searching the given sparse map, where each location is connected
+/// to the orthogonally adjacent locations present in the map whose values are passable.
+pub fn map_neighbours<'a, T, P>(map: &'a HashMap<Point2D, T>, passable: P)
+        -> impl Fn(&Point2D) -> Vec<Point2D> + 'a
+        where P: Fn(&T) -> bool + 'a
+{
+    move |loc: &Point2D| {
+        loc.get_adjacent_points()
+            .into_iter()
+            .filter(|next_loc| map.get(next_loc).is_some_and(&passable))
+            .collect::<Vec<Point2D>>()
+    }
+}
+
+/// Gets a weighted neighbours function for searching the given sparse map, where each location is
+/// connected to the orthogonally adjacent locations present in the map. The cost function gives
+/// the cost of entering a location, or None if the location is impassable.
+pub fn map_weighted_neighbours<'a, T, C>(map: &'a HashMap<Point2D, T>, cost: C)
+        -> impl Fn(&Point2D) -> Vec<(Point2D, u64)> + 'a
+        where C: Fn(&T) -> Option<u64> + 'a
+{
+    move |loc: &Point2D| {
+        loc.get_adjacent_points()
+            .into_iter()
+            .filter_map(|next_loc| Some((next_loc, cost(map.get(&next_loc)?)?)))
+            .collect::<Vec<(Point2D, u64)>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::search::breadth_first_search;
+    use crate::utils::search::dijkstra;
+
+    /// Gets a small grid with walls ('#'), where open cells ('.') cost 1 to enter and lit cells
+    /// ('*') cost 5 to enter.
+    fn get_grid() -> Grid2D<char> {
+        let input = ".#.\n.*.\n...";
+        Grid2D::from_str_with(input, Ok::<char, String>).unwrap()
+    }
+
+    fn get_cost(c: &char) -> Option<u64> {
+        match c {
+            '.' => Some(1),
+            '*' => Some(5),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = get_grid();
+        let neighbours = grid_neighbours(&grid, |c| *c != '#');
+        assert_eq!(vec![Point2D::new(0, 1)], neighbours(&Point2D::new(0, 0)));
+        assert_eq!(
+            vec![Point2D::new(2, 1), Point2D::new(1, 2), Point2D::new(0, 1)],
+            neighbours(&Point2D::new(1, 1))
+        );
+        let result = breadth_first_search(Point2D::new(0, 0), neighbours);
+        assert_eq!(Some(4), result.get_distance(&Point2D::new(2, 0)));
+        assert_eq!(8, result.len());
+    }
+
+    #[test]
+    fn test_grid_weighted_neighbours() {
+        let grid = get_grid();
+        let neighbours = grid_weighted_neighbours(&grid, get_cost);
+        assert_eq!(
+            vec![(Point2D::new(0, 0), 1), (Point2D::new(1, 1), 5), (Point2D::new(0, 2), 1)],
+            neighbours(&Point2D::new(0, 1))
+        );
+        // Going around the lit cell is cheaper than going through it
+        let result = dijkstra(Point2D::new(0, 1), neighbours);
+        assert_eq!(Some(4), result.get_distance(&Point2D::new(2, 1)));
+        assert_eq!(Some(5), result.get_distance(&Point2D::new(1, 1)));
+        assert_eq!(None, result.get_distance(&Point2D::new(1, 0)));
+    }
+
+    #[test]
+    fn test_map_neighbours() {
+        let grid = get_grid();
+        let map = grid.iter().map(|(loc, c)| (loc, *c)).collect::<HashMap<Point2D, char>>();
+        let neighbours = map_neighbours(&map, |c| *c != '#');
+        let weighted_neighbours = map_weighted_neighbours(&map, get_cost);
+        let grid_neighbours = grid_neighbours(&grid, |c| *c != '#');
+        let grid_weighted_neighbours = grid_weighted_neighbours(&grid, get_cost);
+        for loc in grid.iter_points() {
+            assert_eq!(grid_neighbours(&loc), neighbours(&loc));
+            assert_eq!(grid_weighted_neighbours(&loc), weighted_neighbours(&loc));
+        }
+        assert!(neighbours(&Point2D::new(5, 5)).is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use super::SearchResult;
+
+/// Explores the graph reachable from the start node in breadth-first order, using the given
+/// function to generate the neighbours of each node. The distance to each node is the fewest
+/// number of steps needed to reach it from the start node.
+pub fn breadth_first_search<N, F, I>(start: N, mut neighbours: F) -> SearchResult<N>
+        where N: Clone + Eq + Hash, F: FnMut(&N) -> I, I: IntoIterator<Item = N>
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut queue: VecDeque<N> = VecDeque::new();
+    queue.push_back(start);
+    while let Some(node) = queue.pop_front() {
+        let distance = result.get_distance(&node).unwrap();
+        for next_node in neighbours(&node) {
+            if !result.contains(&next_node) {
+                result.record(next_node.clone(), distance + 1, node.clone());
+                queue.push_back(next_node);
+            }
+        }
+        result.record_visit(node);
+    }
+    result
+}
+
+/// Explores the graph reachable from the start node in depth-first order, using the given function
+/// to generate the neighbours of each node. Neighbours are explored in the order generated. The
+/// distance to each node is its depth within the depth-first search tree, which is not necessarily
+/// the shortest distance from the start node.
+pub fn depth_first_search<N, F, I>(start: N, mut neighbours: F) -> SearchResult<N>
+        where N: Clone + Eq + Hash, F: FnMut(&N) -> I, I: IntoIterator<Item = N>
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut visited: HashSet<N> = HashSet::new();
+    // Each stack entry holds a node along with the node it was reached from and its depth
+    let mut stack: Vec<(N, Option<N>, u64)> = vec![(start, None, 0)];
+    while let Some((node, predecessor, depth)) = stack.pop() {
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node.clone());
+        if let Some(predecessor) = predecessor {
+            result.record(node.clone(), depth, predecessor);
+        }
+        // Push neighbours in reverse so the first neighbour generated is explored first
+        let next_nodes = neighbours(&node)
+            .into_iter()
+            .filter(|next_node| !visited.contains(next_node))
+            .collect::<Vec<N>>();
+        for next_node in next_nodes.into_iter().rev() {
+            stack.push((next_node, Some(node.clone()), depth + 1));
+        }
+        result.record_visit(node);
+    }
+    result
+}
+
+/// Explores the graph reachable from the start node using Dijkstra's algorithm, using the given
+/// function to generate the neighbours of each node along with the (non-negative) cost of moving
+/// to each neighbour. The distance to each node is the lowest total cost of reaching it from the
+/// start node.
+pub fn dijkstra<N, F, I>(start: N, mut neighbours: F) -> SearchResult<N>
+        where N: Clone + Eq + Hash, F: FnMut(&N) -> I, I: IntoIterator<Item = (N, u64)>
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut visited: HashSet<N> = HashSet::new();
+    // Queue holds indices into the node list, so nodes do not need to be ordered themselves
+    let mut nodes: Vec<N> = vec![start];
+    let mut queue: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+    queue.push(Reverse((0, 0)));
+    while let Some(Reverse((distance, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node.clone());
+        for (next_node, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if result.get_distance(&next_node).is_none_or(|old| next_distance < old) {
+                result.record(next_node.clone(), next_distance, node.clone());
+                nodes.push(next_node);
+                queue.push(Reverse((next_distance, nodes.len() - 1)));
+            }
+        }
+        result.record_visit(node);
+    }
+    result
+}
+
+/// Finds the lowest-cost path from the start node to a node satisfying the goal predicate using
+/// the A* algorithm. The given function generates the neighbours of each node along with the
+/// (non-negative) cost of moving to each neighbour. The heuristic estimates the remaining cost
+/// from a node to the goal, and must never overestimate it or decrease by more than the cost of a
+/// single move (i.e. it must be consistent). Returns the total cost and the path (including both
+/// ends), or None if no goal node can be reached.
+pub fn a_star<N, F, I, H, G>(start: N, mut neighbours: F, heuristic: H, is_goal: G)
+        -> Option<(u64, Vec<N>)>
+        where N: Clone + Eq + Hash, F: FnMut(&N) -> I, I: IntoIterator<Item = (N, u64)>,
+              H: Fn(&N) -> u64, G: Fn(&N) -> bool
+{
+    let mut result = SearchResult::new(start.clone());
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut queue: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0)));
+    let mut nodes: Vec<N> = vec![start];
+    while let Some(Reverse((_, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        if visited.contains(&node) {
+            continue;
+        }
+        let distance = result.get_distance(&node).unwrap();
+        if is_goal(&node) {
+            return Some((distance, result.get_path(&node).unwrap()));
+        }
+        visited.insert(node.clone());
+        for (next_node, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if result.get_distance(&next_node).is_none_or(|old| next_distance < old) {
+                result.record(next_node.clone(), next_distance, node.clone());
+                let estimate = next_distance + heuristic(&next_node);
+                nodes.push(next_node);
+                queue.push(Reverse((estimate, nodes.len() - 1)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Gets a small weighted directed graph, in which node 'F' cannot be reached from node 'A'.
+    fn get_weighted_graph() -> HashMap<char, Vec<(char, u64)>> {
+        let mut graph: HashMap<char, Vec<(char, u64)>> = HashMap::new();
+        graph.insert('A', vec![('B', 1), ('C', 4)]);
+        graph.insert('B', vec![('C', 2), ('D', 5)]);
+        graph.insert('C', vec![('D', 1)]);
+        graph.insert('D', vec![('E', 3)]);
+        graph.insert('F', vec![('A', 1)]);
+        graph
+    }
+
+    #[test]
+    fn test_breadth_first_search() {
+        let graph = get_weighted_graph();
+        let neighbours = |node: &char| {
+            graph.get(node).map_or(vec![], |edges| edges.iter().map(|x| x.0).collect())
+        };
+        let result = breadth_first_search('A', neighbours);
+        assert_eq!(&vec!['A', 'B', 'C', 'D', 'E'], result.get_visit_order());
+        assert_eq!(Some(1), result.get_distance(&'C'));
+        assert_eq!(Some(3), result.get_distance(&'E'));
+        assert_eq!(Some(vec!['A', 'B', 'D', 'E']), result.get_path(&'E'));
+        assert_eq!(None, result.get_distance(&'F'));
+    }
+
+    #[test]
+    fn test_depth_first_search() {
+        let graph = get_weighted_graph();
+        let neighbours = |node: &char| {
+            graph.get(node).map_or(vec![], |edges| edges.iter().map(|x| x.0).collect())
+        };
+        let result = depth_first_search('A', neighbours);
+        assert_eq!(&vec!['A', 'B', 'C', 'D', 'E'], result.get_visit_order());
+        assert_eq!(Some(2), result.get_distance(&'C'));
+        assert_eq!(Some(vec!['A', 'B', 'C', 'D', 'E']), result.get_path(&'E'));
+        assert_eq!(None, result.get_path(&'F'));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = get_weighted_graph();
+        let result = dijkstra('A', |node: &char| graph.get(node).cloned().unwrap_or_default());
+        assert_eq!(Some(0), result.get_distance(&'A'));
+        assert_eq!(Some(3), result.get_distance(&'C'));
+        assert_eq!(Some(4), result.get_distance(&'D'));
+        assert_eq!(Some(7), result.get_distance(&'E'));
+        assert_eq!(Some(vec!['A', 'B', 'C', 'D', 'E']), result.get_path(&'E'));
+        assert_eq!(None, result.get_distance(&'F'));
+        assert_eq!(None, result.get_path(&'F'));
+        assert_eq!(5, result.len());
+    }
+
+    #[test]
+    fn test_a_star_matches_dijkstra() {
+        let graph = get_weighted_graph();
+        let neighbours = |node: &char| graph.get(node).cloned().unwrap_or_default();
+        let result = dijkstra('A', neighbours);
+        // Exact remaining cost to 'E' is a consistent heuristic, as is no estimate at all
+        let remaining = |node: &char| match node {
+            'A' => 7,
+            'B' => 6,
+            'C' => 4,
+            'D' => 3,
+            _ => 0,
+        };
+        for goal in ['A', 'B', 'C', 'D', 'E'].iter() {
+            let expected = result.get_distance(goal).zip(result.get_path(goal));
+            let heuristic = |node: &char| if *goal == 'E' { remaining(node) } else { 0 };
+            assert_eq!(expected, a_star('A', neighbours, heuristic, |node| node == goal));
+        }
+    }
+
+    #[test]
+    fn test_a_star_unreachable_goal() {
+        let graph = get_weighted_graph();
+        let neighbours = |node: &char| graph.get(node).cloned().unwrap_or_default();
+        assert_eq!(None, a_star('A', neighbours, |_| 0, |node| *node == 'F'));
+        assert_eq!(Some((1, vec!['F', 'A'])), a_star('F', neighbours, |_| 0, |node| *node == 'A'));
+    }
+}
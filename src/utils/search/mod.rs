@@ -0,0 +1,13 @@
+mod adapters;
+mod graphsearch;
+mod searchresult;
+
+pub use self::adapters::grid_neighbours;
+pub use self::adapters::grid_weighted_neighbours;
+pub use self::adapters::map_neighbours;
+pub use self::adapters::map_weighted_neighbours;
+pub use self::graphsearch::a_star;
+pub use self::graphsearch::breadth_first_search;
+pub use self::graphsearch::depth_first_search;
+pub use self::graphsearch::dijkstra;
+pub use self::searchresult::SearchResult;
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Represents the outcome of exploring a graph from a start node, recording the distance to each
+/// node reached along with the node it was first reached from (so that paths can be rebuilt).
+#[derive(Clone, Debug)]
+pub struct SearchResult<N> {
+    start: N,
+    distances: HashMap<N, u64>,
+    predecessors: HashMap<N, N>,
+    visit_order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    /// Creates a new search result containing only the start node, at distance 0.
+    pub(super) fn new(start: N) -> Self {
+        let mut distances: HashMap<N, u64> = HashMap::new();
+        distances.insert(start.clone(), 0);
+        Self {
+            start,
+            distances,
+            predecessors: HashMap::new(),
+            visit_order: vec![],
+        }
+    }
+
+    /// Records the distance to the node and the node it was reached from, replacing any existing
+    /// record for the node.
+    pub(super) fn record(&mut self, node: N, distance: u64, predecessor: N) {
+        self.distances.insert(node.clone(), distance);
+        self.predecessors.insert(node, predecessor);
+    }
+
+    /// Records that the node has been visited (expanded) by the search.
+    pub(super) fn record_visit(&mut self, node: N) {
+        self.visit_order.push(node);
+    }
+
+    /// Gets the node from which the search started.
+    pub fn get_start(&self) -> &N {
+        &self.start
+    }
+
+    /// Checks if the node was reached by the search.
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// Gets the number of nodes reached by the search, including the start node.
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Checks if no nodes were reached by the search. This is never the case, since the start node
+    /// is always reached.
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+
+    /// Gets the distance from the start node to the given node, or None if the node was not
+    /// reached. For breadth-first and depth-first searches, this is the number of steps taken
+    /// along the path found by the search.
+    pub fn get_distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// Gets the distances from the start node to every node reached by the search.
+    pub fn get_distances(&self) -> &HashMap<N, u64> {
+        &self.distances
+    }
+
+    /// Gets the nodes in the order in which they were visited (expanded) by the search, starting
+    /// with the start node.
+    pub fn get_visit_order(&self) -> &Vec<N> {
+        &self.visit_order
+    }
+
+    /// Rebuilds the path found by the search from the start node to the given node (including
+    /// both ends), or None if the node was not reached.
+    pub fn get_path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+        let mut path: Vec<N> = vec![node.clone()];
+        let mut current = node;
+        while let Some(predecessor) = self.predecessors.get(current) {
+            path.push(predecessor.clone());
+            current = predecessor;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
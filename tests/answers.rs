@@ -0,0 +1,125 @@
+use std::fs;
+
+use advent_of_code_2020::registry::SolutionRegistry;
+
+use serde::Deserialize;
+
+/// Location of the answers manifest, relative to the crate root.
+const MANIFEST_PATH: &str = "./input/2020/answers.toml";
+/// Directory containing the puzzle input files listed in the answers manifest.
+const INPUT_DIR: &str = "./input/2020";
+/// Year of the puzzles covered by the answers manifest.
+const YEAR: u32 = 2020;
+
+/// Contents of the answers manifest.
+#[derive(Deserialize)]
+struct AnswersManifest {
+    answer: Vec<ExpectedAnswer>,
+}
+
+/// Expected answer for one part of a puzzle, when solved using the given input file.
+#[derive(Deserialize)]
+struct ExpectedAnswer {
+    day: u32,
+    part: u32,
+    input: String,
+    answer: String,
+}
+
+/// Reads the answers manifest.
+fn read_manifest() -> AnswersManifest {
+    let raw_manifest = fs::read_to_string(MANIFEST_PATH).unwrap();
+    toml::from_str(&raw_manifest).unwrap()
+}
+
+/// Checks every entry in the answers manifest for the given day, failing with a report of all the
+/// incorrect answers for that day.
+fn check_answers_for_day(day: u32) {
+    let manifest = read_manifest();
+    let registry = SolutionRegistry::with_all_solutions();
+    // Check every entry before failing, so that all incorrect answers are reported together
+    let mut failures: Vec<String> = vec![];
+    for expected in manifest.answer.iter().filter(|expected| expected.day == day) {
+        let label = format!("Day {} - Part {} ({})", expected.day, expected.part, expected.input);
+        let entry = match registry.get(YEAR, expected.day, expected.part) {
+            Some(entry) => entry,
+            None => {
+                failures.push(format!("{}: no solution registered", label));
+                continue;
+            }
+        };
+        let input = match fs::read_to_string(format!("{}/{}", INPUT_DIR, expected.input)) {
+            Ok(input) => input,
+            Err(e) => {
+                failures.push(format!("{}: failed to read input file: {}", label, e));
+                continue;
+            }
+        };
+        // Remove trailing newlines from the input, consistent with inputs given by cargo-aoc
+        match entry.run(input.trim_end_matches('\n')) {
+            Ok(run) => {
+                let actual = run.answer.to_string();
+                if actual != expected.answer {
+                    failures.push(format!(
+                        "{}: expected {}, got {}", label, expected.answer, actual
+                    ));
+                }
+            },
+            Err(e) => failures.push(format!("{}: {}", label, e)),
+        }
+    }
+    assert!(failures.is_empty(), "incorrect answers:\n{}", failures.join("\n"));
+}
+
+/// Generates a separate test for each day checking its entries in the answers manifest, so that
+/// the days are checked in parallel and a failing day does not hide the results of later days.
+macro_rules! answers_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_answers_for_day($day);
+            }
+        )*
+    };
+}
+
+answers_tests! {
+    test_answers_day_01: 1,
+    test_answers_day_02: 2,
+    test_answers_day_03: 3,
+    test_answers_day_04: 4,
+    test_answers_day_05: 5,
+    test_answers_day_06: 6,
+    test_answers_day_07: 7,
+    test_answers_day_08: 8,
+    test_answers_day_09: 9,
+    test_answers_day_10: 10,
+    test_answers_day_11: 11,
+    test_answers_day_12: 12,
+    test_answers_day_13: 13,
+    test_answers_day_14: 14,
+    test_answers_day_15: 15,
+    test_answers_day_16: 16,
+    test_answers_day_17: 17,
+    test_answers_day_18: 18,
+    test_answers_day_19: 19,
+    test_answers_day_20: 20,
+    test_answers_day_21: 21,
+    test_answers_day_22: 22,
+    test_answers_day_23: 23,
+    test_answers_day_24: 24,
+    test_answers_day_25: 25,
+}
+
+#[test]
+fn test_answers_manifest_days() {
+    // Entries for any other day would not be checked by the tests above
+    let manifest = read_manifest();
+    for expected in manifest.answer.iter() {
+        assert!(
+            (1..=25).contains(&expected.day),
+            "Day {} - Part {} ({}): day out of range", expected.day, expected.part, expected.input
+        );
+    }
+}